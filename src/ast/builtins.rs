@@ -0,0 +1,422 @@
+/* BUILTINS.rs
+ *   by Lut99
+ *
+ * Created:
+ *   17 Oct 2026, 13:02:17
+ * Last edited:
+ *   17 Oct 2026, 13:02:17
+ * Auto updated?
+ *   Yes
+ *
+ * Description:
+ *   Contains the registry of built-in functions that can be called from
+ *   expressions.
+**/
+
+use crate::ast::parser::ValueWidth;
+use crate::binary::BinaryFile;
+
+
+/***** LIBRARY TYPES *****/
+/// Type shortcut for a half-open range, as its start and end (in that order).
+pub type Range = (u128, u128);
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when calling a built-in function.
+#[derive(Debug)]
+pub enum BuiltinError {
+    /// The function would have divided by zero
+    DivisionByZero,
+    /// The function's result did not fit in 128-bits
+    Overflow,
+    /// The function's ranges have a gap between them
+    Disjoint,
+    /// The function reads from a file, but no file has been opened
+    NoFile,
+    /// The function reads bytes that lie outside of the opened file
+    OutOfBounds{ n_bytes: usize, size: usize },
+    /// The function needs the segments of an ELF file, but the opened file is not one
+    NotElf{ reason: String },
+    /// The function needs the sections of a PE file, but the opened file is not one
+    NotPe{ reason: String },
+    /// The function translates an address or offset that lies outside of every segment or section that maps it
+    Unmapped{ what: &'static str, region: &'static str },
+    /// The function translates an RVA in a section that has no data in the file at all
+    NoFileData{ section: usize },
+}
+
+impl std::fmt::Display for BuiltinError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuiltinError::DivisionByZero => { write!(f, "Division by zero") }
+            BuiltinError::Overflow       => { write!(f, "Overflow occurred") }
+            BuiltinError::Disjoint       => { write!(f, "Disjoint ranges") }
+            BuiltinError::NoFile         => { write!(f, "No file opened (see 'open <path>')") }
+            BuiltinError::OutOfBounds{ n_bytes, size } => { write!(f, "Out-of-bounds read of {} byte{} in the {}-byte file", n_bytes, if *n_bytes == 1 { "" } else { "s" }, size) }
+            BuiltinError::NotElf{ reason } => { write!(f, "The opened file is not a valid ELF file: {}", reason) }
+            BuiltinError::NotPe{ reason }  => { write!(f, "The opened file is not a valid PE file: {}", reason) }
+            BuiltinError::Unmapped{ what, region } => { write!(f, "{} lies outside of every {}", what, region) }
+            BuiltinError::NoFileData{ section }    => { write!(f, "RVA lies in section {}, which has no data in the file", section) }
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Aligns the given value down to the given alignment.
+///
+/// **Arguments**
+///  * `value`: The value to align.
+///  * `alignment`: The alignment to align to.
+///  * `signed`: Whether the values should be interpreted as signed (two's complement) values.
+///
+/// **Returns**
+/// The aligned value, or a BuiltinError if the alignment is zero or the result does not fit.
+fn align_down(value: u128, alignment: u128, signed: bool) -> Result<u128, BuiltinError> {
+    if alignment == 0 { return Err(BuiltinError::DivisionByZero); }
+    if signed {
+        // Use the euclidean remainder to make sure negative values are aligned downwards too
+        let rem = (value as i128).checked_rem_euclid(alignment as i128).ok_or(BuiltinError::Overflow)?;
+        return Ok((value as i128).checked_sub(rem).ok_or(BuiltinError::Overflow)? as u128);
+    }
+    return Ok(value - value % alignment);
+}
+
+/// Aligns the given value up to the given alignment.
+///
+/// **Arguments**
+///  * `value`: The value to align.
+///  * `alignment`: The alignment to align to.
+///  * `signed`: Whether the values should be interpreted as signed (two's complement) values.
+///
+/// **Returns**
+/// The aligned value, or a BuiltinError if the alignment is zero or the result does not fit.
+fn align_up(value: u128, alignment: u128, signed: bool) -> Result<u128, BuiltinError> {
+    let down = align_down(value, alignment, signed)?;
+    if down == value { return Ok(value); }
+    if signed {
+        return Ok((down as i128).checked_add((alignment as i128).abs()).ok_or(BuiltinError::Overflow)? as u128);
+    }
+    return down.checked_add(alignment).ok_or(BuiltinError::Overflow);
+}
+
+/// Compares two values, interpreting them as signed values if needed.
+///
+/// **Arguments**
+///  * `lhs`: The lefthand-side value.
+///  * `rhs`: The righthand-side value.
+///  * `signed`: Whether the values should be interpreted as signed (two's complement) values.
+///
+/// **Returns**
+/// Whether lhs is strictly smaller than rhs.
+fn less(lhs: u128, rhs: u128, signed: bool) -> bool {
+    if signed { (lhs as i128) < (rhs as i128) } else { lhs < rhs }
+}
+
+/// Reverses the order of the bytes in the given value.
+///
+/// **Arguments**
+///  * `value`: The value to swap.
+///  * `width`: The width of the value to swap, which determines how many bytes are swapped.
+///  * `signed`: Whether the value should be interpreted as a signed (two's complement) value, in which case the result is sign-extended again.
+///
+/// **Returns**
+/// The swapped value, or a BuiltinError if the value does not fit in the width.
+pub fn bswap(value: u128, width: ValueWidth, signed: bool) -> Result<u128, BuiltinError> {
+    if !width.fits(value, signed) { return Err(BuiltinError::Overflow); }
+    let swapped = (value & width.mask()).swap_bytes() >> (128 - width.bits());
+    return Ok(width.wrap(swapped, signed));
+}
+
+
+
+
+
+/***** BUILTINS *****/
+/// Implements the 'align_up' function.
+fn builtin_align_up(args: &[u128], signed: bool) -> Result<u128, BuiltinError> {
+    return align_up(args[0], args[1], signed);
+}
+
+/// Implements the 'align_down' function.
+fn builtin_align_down(args: &[u128], signed: bool) -> Result<u128, BuiltinError> {
+    return align_down(args[0], args[1], signed);
+}
+
+/// Implements the 'min' function.
+fn builtin_min(args: &[u128], signed: bool) -> Result<u128, BuiltinError> {
+    return Ok(if less(args[1], args[0], signed) { args[1] } else { args[0] });
+}
+
+/// Implements the 'max' function.
+fn builtin_max(args: &[u128], signed: bool) -> Result<u128, BuiltinError> {
+    return Ok(if less(args[0], args[1], signed) { args[1] } else { args[0] });
+}
+
+/// Implements the 'abs_diff' function.
+fn builtin_abs_diff(args: &[u128], signed: bool) -> Result<u128, BuiltinError> {
+    if signed {
+        // The difference between two signed values may not fit in a signed value anymore
+        let diff = (args[0] as i128).abs_diff(args[1] as i128);
+        if diff > i128::MAX as u128 { return Err(BuiltinError::Overflow); }
+        return Ok(diff);
+    }
+    return Ok(args[0].abs_diff(args[1]));
+}
+
+/// Implements the 'f32bits' function.
+fn builtin_f32bits(args: &[f64]) -> Result<u128, BuiltinError> {
+    // Narrowing a float that's too large for 32 bits silently results in infinity
+    let value = args[0] as f32;
+    if value.is_infinite() && args[0].is_finite() { return Err(BuiltinError::Overflow); }
+    return Ok(value.to_bits() as u128);
+}
+
+/// Implements the 'f64bits' function.
+fn builtin_f64bits(args: &[f64]) -> Result<u128, BuiltinError> {
+    return Ok(args[0].to_bits() as u128);
+}
+
+/// Implements the 'len' function.
+fn builtin_len(args: &[Range], signed: bool) -> Result<u128, BuiltinError> {
+    let (start, end) = args[0];
+    if signed {
+        // The length of a signed range may not fit in a signed value anymore
+        let len = (end as i128).abs_diff(start as i128);
+        if len > i128::MAX as u128 { return Err(BuiltinError::Overflow); }
+        return Ok(len);
+    }
+    return Ok(end - start);
+}
+
+/// Implements the 'overlaps' function.
+fn builtin_overlaps(args: &[Range], signed: bool) -> Result<bool, BuiltinError> {
+    let ((start1, end1), (start2, end2)) = (args[0], args[1]);
+    return Ok(less(start1, end2, signed) && less(start2, end1, signed));
+}
+
+/// Implements the 'intersect' function.
+fn builtin_intersect(args: &[Range], signed: bool) -> Result<Range, BuiltinError> {
+    let ((start1, end1), (start2, end2)) = (args[0], args[1]);
+    let start = if less(start1, start2, signed) { start2 } else { start1 };
+    let end   = if less(end1, end2, signed) { end1 } else { end2 };
+    if less(end, start, signed) { return Err(BuiltinError::Disjoint); }
+    return Ok((start, end));
+}
+
+/// Implements the 'union' function.
+fn builtin_union(args: &[Range], signed: bool) -> Result<Range, BuiltinError> {
+    let ((start1, end1), (start2, end2)) = (args[0], args[1]);
+    // The ranges have to overlap or touch, or else the union isn't a single range
+    if less(end1, start2, signed) || less(end2, start1, signed) { return Err(BuiltinError::Disjoint); }
+    let start = if less(start1, start2, signed) { start1 } else { start2 };
+    let end   = if less(end1, end2, signed) { end2 } else { end1 };
+    return Ok((start, end));
+}
+
+
+
+
+
+/***** LIBRARY STRUCTS *****/
+/// Defines the function that implements a built-in, which also determines whether it works on values or on ranges.
+pub enum BuiltinFunc {
+    /// The function computes a value based on the (raw) argument values and whether they are signed.
+    Value(fn(&[u128], bool) -> Result<u128, BuiltinError>),
+    /// The function computes a value based on the argument ranges (as start and end pairs) and whether they are signed.
+    RangeToValue(fn(&[Range], bool) -> Result<u128, BuiltinError>),
+    /// The function computes a boolean based on the argument ranges and whether they are signed.
+    RangeToBool(fn(&[Range], bool) -> Result<bool, BuiltinError>),
+    /// The function computes a range based on the argument ranges and whether they are signed.
+    RangeToRange(fn(&[Range], bool) -> Result<Range, BuiltinError>),
+    /// The function computes the bits of the argument floats (where integer arguments are converted to floats), which result in a value of the given width.
+    FloatToBits(fn(&[f64]) -> Result<u128, BuiltinError>, ValueWidth),
+    /// The function reverses the order of the bytes in the argument value as a value of the given width, which is also the width of the result.
+    Swap(ValueWidth),
+    /// The function reads a value of the given width at the argument offset in the opened file, in little-endian (true) or big-endian (false) byte order.
+    Read(ValueWidth, bool),
+    /// The function translates the argument virtual address to a file offset (true) or the other way around (false), using the PT_LOAD segments of the opened ELF file.
+    Elf(bool),
+    /// The function translates the argument RVA to a file offset (true) or the other way around (false), using the sections of the opened PE file.
+    Pe(bool),
+}
+
+/// Defines a single built-in function.
+pub struct Builtin {
+    /// The name with which the function is called.
+    pub name: &'static str,
+    /// The names of the arguments of the function (which also determines its arity).
+    pub args: &'static [&'static str],
+    /// A short description of the function, used in the help menu.
+    pub description: &'static str,
+    /// The function that computes the result.
+    pub func: BuiltinFunc,
+}
+
+impl Builtin {
+    /// Returns whether the function takes ranges as arguments instead of values.
+    #[inline]
+    pub fn takes_ranges(&self) -> bool {
+        match self.func {
+            BuiltinFunc::Value(_)          |
+            BuiltinFunc::FloatToBits(_, _) |
+            BuiltinFunc::Swap(_)           |
+            BuiltinFunc::Read(_, _)        |
+            BuiltinFunc::Elf(_)            |
+            BuiltinFunc::Pe(_)             => { false }
+            _                              => { true }
+        }
+    }
+
+    /// Returns whether the function results in a range instead of a value.
+    #[inline]
+    pub fn returns_range(&self) -> bool {
+        match self.func {
+            BuiltinFunc::RangeToRange(_) => { true }
+            _                            => { false }
+        }
+    }
+}
+
+/// The registry of all built-in functions.
+pub const BUILTINS: &[Builtin] = &[
+    Builtin{ name: "align_up",   args: &["value", "alignment"], description: "Rounds the value up to the nearest multiple of the alignment.",   func: BuiltinFunc::Value(builtin_align_up) },
+    Builtin{ name: "align_down", args: &["value", "alignment"], description: "Rounds the value down to the nearest multiple of the alignment.", func: BuiltinFunc::Value(builtin_align_down) },
+    Builtin{ name: "min",        args: &["lhs", "rhs"],         description: "Returns the smallest of the two values.",                         func: BuiltinFunc::Value(builtin_min) },
+    Builtin{ name: "max",        args: &["lhs", "rhs"],         description: "Returns the largest of the two values.",                          func: BuiltinFunc::Value(builtin_max) },
+    Builtin{ name: "abs_diff",   args: &["lhs", "rhs"],         description: "Returns the absolute difference between the two values.",          func: BuiltinFunc::Value(builtin_abs_diff) },
+    Builtin{ name: "bswap16",    args: &["value"],              description: "Reverses the byte order of the value as a 16-bit value.",         func: BuiltinFunc::Swap(ValueWidth::U16) },
+    Builtin{ name: "bswap32",    args: &["value"],              description: "Reverses the byte order of the value as a 32-bit value.",         func: BuiltinFunc::Swap(ValueWidth::U32) },
+    Builtin{ name: "bswap64",    args: &["value"],              description: "Reverses the byte order of the value as a 64-bit value.",         func: BuiltinFunc::Swap(ValueWidth::U64) },
+    Builtin{ name: "f32bits",    args: &["float"],              description: "Returns the bits of the float as a 32-bit IEEE-754 float.",       func: BuiltinFunc::FloatToBits(builtin_f32bits, ValueWidth::U32) },
+    Builtin{ name: "f64bits",    args: &["float"],              description: "Returns the bits of the float as a 64-bit IEEE-754 float.",       func: BuiltinFunc::FloatToBits(builtin_f64bits, ValueWidth::U64) },
+    Builtin{ name: "byte",       args: &["offset"],             description: "Reads the byte at the offset in the opened file.",                func: BuiltinFunc::Read(ValueWidth::U8, true) },
+    Builtin{ name: "u16le",      args: &["offset"],             description: "Reads a little-endian 16-bit value at the offset in the file.",   func: BuiltinFunc::Read(ValueWidth::U16, true) },
+    Builtin{ name: "u16be",      args: &["offset"],             description: "Reads a big-endian 16-bit value at the offset in the file.",      func: BuiltinFunc::Read(ValueWidth::U16, false) },
+    Builtin{ name: "u32le",      args: &["offset"],             description: "Reads a little-endian 32-bit value at the offset in the file.",   func: BuiltinFunc::Read(ValueWidth::U32, true) },
+    Builtin{ name: "u32be",      args: &["offset"],             description: "Reads a big-endian 32-bit value at the offset in the file.",      func: BuiltinFunc::Read(ValueWidth::U32, false) },
+    Builtin{ name: "u64le",      args: &["offset"],             description: "Reads a little-endian 64-bit value at the offset in the file.",   func: BuiltinFunc::Read(ValueWidth::U64, true) },
+    Builtin{ name: "u64be",      args: &["offset"],             description: "Reads a big-endian 64-bit value at the offset in the file.",      func: BuiltinFunc::Read(ValueWidth::U64, false) },
+    Builtin{ name: "vaddr2off",  args: &["vaddr"],              description: "Translates the virtual address to an offset in the ELF file.",   func: BuiltinFunc::Elf(true) },
+    Builtin{ name: "off2vaddr",  args: &["offset"],             description: "Translates the offset in the ELF file to a virtual address.",    func: BuiltinFunc::Elf(false) },
+    Builtin{ name: "rva2off",    args: &["rva"],                description: "Translates the RVA to an offset in the PE file.",                func: BuiltinFunc::Pe(true) },
+    Builtin{ name: "off2rva",    args: &["offset"],             description: "Translates the offset in the PE file to an RVA.",                func: BuiltinFunc::Pe(false) },
+    Builtin{ name: "len",        args: &["range"],              description: "Returns the length of the range.",                                func: BuiltinFunc::RangeToValue(builtin_len) },
+    Builtin{ name: "overlaps",   args: &["lhs", "rhs"],         description: "Returns whether the two ranges overlap.",                         func: BuiltinFunc::RangeToBool(builtin_overlaps) },
+    Builtin{ name: "intersect",  args: &["lhs", "rhs"],         description: "Returns the range where the two ranges overlap.",                 func: BuiltinFunc::RangeToRange(builtin_intersect) },
+    Builtin{ name: "union",      args: &["lhs", "rhs"],         description: "Returns the range covering two overlapping or touching ranges.",  func: BuiltinFunc::RangeToRange(builtin_union) },
+];
+
+
+
+
+
+/***** LIBRARY FUNCTIONS *****/
+/// Looks up the built-in function with the given name.
+///
+/// **Arguments**
+///  * `name`: The name of the function to look for.
+///
+/// **Returns**
+/// A reference to the Builtin, or None if no such function exists.
+pub fn get(name: &str) -> Option<&'static Builtin> {
+    return BUILTINS.iter().find(|builtin| builtin.name == name);
+}
+
+/// Reads a value from the given file.
+///
+/// **Arguments**
+///  * `file`: The opened file to read from, if any.
+///  * `offset`: The offset of the value in the file.
+///  * `width`: The width of the value, which determines how many bytes are read.
+///  * `little_endian`: Whether the value is stored in little-endian (true) or big-endian (false) byte order.
+///  * `signed`: Whether the offset and the value should be interpreted as signed (two's complement) values, in which case the value is sign-extended.
+///
+/// **Returns**
+/// The read value, or a BuiltinError if no file is opened or the value lies (partly) outside of it.
+pub fn read(file: Option<&BinaryFile>, offset: u128, width: ValueWidth, little_endian: bool, signed: bool) -> Result<u128, BuiltinError> {
+    let file = file.ok_or(BuiltinError::NoFile)?;
+    let n_bytes = (width.bits() / 8) as usize;
+    if signed && (offset as i128) < 0 { return Err(BuiltinError::OutOfBounds{ n_bytes: n_bytes, size: file.len() }); }
+    let bytes = file.read(offset, n_bytes).ok_or(BuiltinError::OutOfBounds{ n_bytes: n_bytes, size: file.len() })?;
+
+    // Glue the bytes together in the given byte order
+    let mut value: u128 = 0;
+    if little_endian {
+        for b in bytes.iter().rev() { value = (value << 8) | *b as u128; }
+    } else {
+        for b in bytes.iter() { value = (value << 8) | *b as u128; }
+    }
+    return Ok(width.wrap(value, signed));
+}
+
+/// Translates between a virtual address and a file offset using the PT_LOAD segments of the given file.
+///
+/// **Arguments**
+///  * `file`: The opened file with the segments, if any.
+///  * `value`: The virtual address or file offset to translate.
+///  * `to_offset`: Whether to translate a virtual address to a file offset (true) or a file offset to a virtual address (false).
+///  * `signed`: Whether the value should be interpreted as a signed (two's complement) value.
+///
+/// **Returns**
+/// The translated value, together with a note if the address lies in the BSS part of its segment (past its size in the file). Returns a BuiltinError if no ELF file is opened or the value lies outside of every segment.
+pub fn translate_elf(file: Option<&BinaryFile>, value: u128, to_offset: bool, signed: bool) -> Result<(u128, Option<String>), BuiltinError> {
+    let elf = file.ok_or(BuiltinError::NoFile)?.elf().map_err(|reason| BuiltinError::NotElf{ reason: reason.to_string() })?;
+    let what = if to_offset { "Address" } else { "Offset" };
+    if signed && (value as i128) < 0 { return Err(BuiltinError::Unmapped{ what, region: "PT_LOAD segment" }); }
+    let value = u64::try_from(value).map_err(|_| BuiltinError::Unmapped{ what, region: "PT_LOAD segment" })?;
+
+    // Find the segment and translate relative to it
+    if to_offset {
+        let (i, segment) = elf.segment_at_vaddr(value).ok_or(BuiltinError::Unmapped{ what, region: "PT_LOAD segment" })?;
+        let offset = segment.offset as u128 + (value - segment.vaddr) as u128;
+        if value - segment.vaddr >= segment.filesz {
+            return Ok((offset, Some(format!("Address lies in the BSS part of segment {} (past its size in the file), so offset 0x{:X} is not backed by the file", i, offset))));
+        }
+        return Ok((offset, None));
+    } else {
+        let (_, segment) = elf.segment_at_offset(value).ok_or(BuiltinError::Unmapped{ what, region: "PT_LOAD segment" })?;
+        return Ok((segment.vaddr as u128 + (value - segment.offset) as u128, None));
+    }
+}
+
+/// Translates between an RVA and a file offset using the sections of the given file, where the headers are mapped as-is.
+///
+/// **Arguments**
+///  * `file`: The opened file with the sections, if any.
+///  * `value`: The RVA or file offset to translate.
+///  * `to_offset`: Whether to translate an RVA to a file offset (true) or a file offset to an RVA (false).
+///  * `signed`: Whether the value should be interpreted as a signed (two's complement) value.
+///
+/// **Returns**
+/// The translated value, together with a note if the RVA lies in the part of its section that's not in the file. Returns a BuiltinError if no PE file is opened, the value lies outside of the headers and every section or the RVA lies in a section without any data in the file.
+pub fn translate_pe(file: Option<&BinaryFile>, value: u128, to_offset: bool, signed: bool) -> Result<(u128, Option<String>), BuiltinError> {
+    let pe = file.ok_or(BuiltinError::NoFile)?.pe().map_err(|reason| BuiltinError::NotPe{ reason: reason.to_string() })?;
+    let what = if to_offset { "RVA" } else { "Offset" };
+    if signed && (value as i128) < 0 { return Err(BuiltinError::Unmapped{ what, region: "section" }); }
+    let value = u64::try_from(value).map_err(|_| BuiltinError::Unmapped{ what, region: "section" })?;
+
+    // The headers are loaded at the start of the image, so they are the same in either direction
+    if value < pe.size_of_headers { return Ok((value as u128, None)); }
+
+    // Otherwise, find the section and translate relative to it
+    if to_offset {
+        let (i, section) = pe.section_at_rva(value).ok_or(BuiltinError::Unmapped{ what, region: "section" })?;
+        if section.raw_size == 0 { return Err(BuiltinError::NoFileData{ section: i }); }
+        let offset = section.raw as u128 + (value - section.rva) as u128;
+        if value - section.rva >= section.raw_size {
+            return Ok((offset, Some(format!("RVA lies in the uninitialized part of section {} (past its size in the file), so offset 0x{:X} is not backed by the file", i, offset))));
+        }
+        return Ok((offset, None));
+    } else {
+        let (_, section) = pe.section_at_offset(value).ok_or(BuiltinError::Unmapped{ what, region: "section" })?;
+        return Ok((section.rva as u128 + (value - section.raw) as u128, None));
+    }
+}
@@ -16,3 +16,4 @@ pub mod symbols;
 pub mod tokenizer;
 pub mod parser;
 pub mod symbol_table;
+pub mod builtins;
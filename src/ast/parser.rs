@@ -32,6 +32,41 @@ enum ParserState {
     Expr,
     /// We've seen one Expr and an equals
     Expr_Equals,
+    /// We've seen one Expr and a colon
+    Expr_Colon,
+
+    /// We've seen one CmpExpr
+    CmpExpr,
+
+    /// We've seen one RangeExpr
+    RangeExpr,
+    /// We've seen a RangeExpr followed by a comparison
+    RangeExpr_Cmp,
+
+    /// We've seen one BitOrExpr
+    BitOrExpr,
+    /// We've seen a BitOrExpr followed by a range sign
+    BitOrExpr_Range,
+
+    /// We've seen one BitXorExpr
+    BitXorExpr,
+    /// We've seen a BitXorExpr followed by an or
+    BitXorExpr_Or,
+
+    /// We've seen one BitAndExpr
+    BitAndExpr,
+    /// We've seen a BitAndExpr followed by a xor
+    BitAndExpr_Xor,
+
+    /// We've seen one ShiftExpr
+    ShiftExpr,
+    /// We've seen a ShiftExpr followed by an and
+    ShiftExpr_And,
+
+    /// We've seen one SumExpr
+    SumExpr,
+    /// We've seen a SumExpr followed by a left OR a right shift
+    SumExpr_Shift,
 
     /// We've seen one Term
     Term,
@@ -40,13 +75,20 @@ enum ParserState {
 
     /// We've seen one Factor
     Factor,
-    /// We've seen a Factor followed by a multiplication OR a division
-    Factor_MultiplyORDivide,
+    /// We've seen a Factor followed by a multiplication OR a division OR a modulo
+    Factor_MultiplyORDivideORModulo,
+    /// We've seen a Factor followed by a power
+    Factor_Power,
 
     /// We've seen the right bracket
     RBracket,
     /// We've seen the right bracket followed by an expression
     RBracket_Expr,
+
+    /// We've seen the right square bracket
+    RSquare,
+    /// We've seen the right square bracket followed by an expression
+    RSquare_Expr,
 }
 
 /// Defines all constant types in the AST.
@@ -61,14 +103,54 @@ pub enum ValueKind {
     Hexadecimal,
     /// The binary constant type
     Binary,
+    /// The octal constant type
+    Octal,
+    /// The boolean type of comparison results
+    Boolean,
+    /// The constant type for any other radix (from 2 up to and including 36), which is carried along
+    Radix(u32),
+    /// The display type that shows the bits of 32- or 64-bit values as the IEEE-754 float they encode
+    Float,
+}
+
+impl ValueKind {
+    /// Returns the ValueKind that represents values in the given radix.
+    /// 
+    /// **Arguments**
+    ///  * `radix`: The radix of the values (from 2 up to and including 36).
+    /// 
+    /// **Returns**  
+    /// The matching ValueKind, which is one of the dedicated kinds for binary, octal, decimal and hexadecimal values or a generic Radix otherwise.
+    pub fn from_radix(radix: u32) -> ValueKind {
+        match radix {
+            2  => { ValueKind::Binary }
+            8  => { ValueKind::Octal }
+            10 => { ValueKind::Decimal }
+            16 => { ValueKind::Hexadecimal }
+            _  => { ValueKind::Radix(radix) }
+        }
+    }
+
+    /// Returns whether values of this kind are written as bit patterns, i.e., as hexadecimal, binary or octal values, or as the float that their bits encode.
+    /// 
+    /// **Returns**  
+    /// True if the kind is Hexadecimal, Binary, Octal or Float, or false otherwise.
+    pub fn is_bit_pattern(&self) -> bool {
+        return matches!(self, ValueKind::Hexadecimal | ValueKind::Binary | ValueKind::Octal | ValueKind::Float);
+    }
 }
 
 impl From<TerminalKind> for ValueKind {
     fn from(val: TerminalKind) -> Self {
         match val {
-            TerminalKind::DEC(_) | TerminalKind::TODEC => { ValueKind::Decimal }
+            TerminalKind::DEC(_) | TerminalKind::TODEC |
+            TerminalKind::TOSDEC                       => { ValueKind::Decimal }
             TerminalKind::HEX(_) | TerminalKind::TOHEX => { ValueKind::Hexadecimal }
             TerminalKind::BIN(_) | TerminalKind::TOBIN => { ValueKind::Binary }
+            TerminalKind::OCT(_) | TerminalKind::TOOCT => { ValueKind::Octal }
+            TerminalKind::RADIX(radix, _) |
+            TerminalKind::TOBASE(radix)                => { ValueKind::from_radix(radix) }
+            TerminalKind::TOFLOAT                      => { ValueKind::Float }
             _                                          => { ValueKind::Undefined }
         }
     }
@@ -83,8 +165,19 @@ impl std::str::FromStr for ValueKind {
             return Ok(ValueKind::Hexadecimal);
         } else if s.to_lowercase().eq("binary") {
             return Ok(ValueKind::Binary);
+        } else if s.to_lowercase().eq("octal") {
+            return Ok(ValueKind::Octal);
+        } else if s.to_lowercase().eq("boolean") {
+            return Ok(ValueKind::Boolean);
+        } else if s.to_lowercase().eq("float") {
+            return Ok(ValueKind::Float);
         } else if s.to_lowercase().eq("undefined") {
             return Ok(ValueKind::Undefined);
+        } else if s.to_lowercase().starts_with("radix(") && s.ends_with(")") {
+            // Parse the radix in between the brackets
+            if let Ok(radix) = s[6..s.len() - 1].parse::<u32>() {
+                if (2..=36).contains(&radix) { return Ok(ValueKind::from_radix(radix)); }
+            }
         }
 
         // Otherwise, return err
@@ -98,6 +191,144 @@ impl From<ValueKind> for String {
     }
 }
 
+/// Defines all value widths in the AST.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum ValueWidth {
+    /// Meta value for when no width is defined, in which case the width is taken from the context
+    Undefined,
+
+    /// 8-bit values
+    U8,
+    /// 16-bit values
+    U16,
+    /// 32-bit values
+    U32,
+    /// 64-bit values
+    U64,
+    /// 128-bit values
+    U128,
+}
+
+impl ValueWidth {
+    /// Returns the number of bits in this width.
+    /// 
+    /// **Returns**  
+    /// The number of bits, which is 0 for Undefined.
+    pub fn bits(&self) -> u32 {
+        match self {
+            ValueWidth::Undefined => { 0 }
+            ValueWidth::U8        => { 8 }
+            ValueWidth::U16       => { 16 }
+            ValueWidth::U32       => { 32 }
+            ValueWidth::U64       => { 64 }
+            ValueWidth::U128      => { 128 }
+        }
+    }
+
+    /// Returns this width, unless it is Undefined, in which case the given width is returned.
+    /// 
+    /// **Arguments**
+    ///  * `default`: The width to return if this one is Undefined.
+    /// 
+    /// **Returns**  
+    /// The resolved ValueWidth.
+    pub fn or(&self, default: ValueWidth) -> ValueWidth {
+        if *self == ValueWidth::Undefined { default } else { *self }
+    }
+
+    /// Returns a mask with all bits of this width set.
+    /// 
+    /// **Returns**  
+    /// The mask as an u128.
+    pub fn mask(&self) -> u128 {
+        if self.bits() >= 128 { u128::MAX } else { (1 << self.bits()) - 1 }
+    }
+
+    /// Checks whether the given value fits in this width.
+    /// 
+    /// **Arguments**
+    ///  * `value`: The value to check. If signed, it's interpreted as a two's complement i128.
+    ///  * `signed`: Whether the value is signed.
+    /// 
+    /// **Returns**  
+    /// True if the value can be represented with this width, or false otherwise.
+    pub fn fits(&self, value: u128, signed: bool) -> bool {
+        if signed {
+            return self.sign_extend(value) == value;
+        } else {
+            return value & !self.mask() == 0;
+        }
+    }
+
+    /// Wraps the given value around this width, i.e., throws away any bits that don't fit.
+    /// 
+    /// **Arguments**
+    ///  * `value`: The value to wrap. If signed, it's interpreted as a two's complement i128.
+    ///  * `signed`: Whether the value is signed, in which case the result is sign-extended again.
+    /// 
+    /// **Returns**  
+    /// The wrapped value.
+    pub fn wrap(&self, value: u128, signed: bool) -> u128 {
+        if signed { self.sign_extend(value) } else { value & self.mask() }
+    }
+
+    /// Sign-extends the lower bits of the given value that fall within this width to the full 128 bits.
+    /// 
+    /// **Arguments**
+    ///  * `value`: The value to sign-extend.
+    /// 
+    /// **Returns**  
+    /// The sign-extended value.
+    fn sign_extend(&self, value: u128) -> u128 {
+        let shift = 128 - self.bits();
+        return (((value << shift) as i128) >> shift) as u128;
+    }
+}
+
+impl From<TerminalKind> for ValueWidth {
+    fn from(val: TerminalKind) -> Self {
+        match val {
+            TerminalKind::TOU8   => { ValueWidth::U8 }
+            TerminalKind::TOU16  => { ValueWidth::U16 }
+            TerminalKind::TOU32  => { ValueWidth::U32 }
+            TerminalKind::TOU64  => { ValueWidth::U64 }
+            TerminalKind::TOU128 => { ValueWidth::U128 }
+            _                    => { ValueWidth::Undefined }
+        }
+    }
+}
+impl std::str::FromStr for ValueWidth {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Try to read, either as the type name or as the number of bits
+        let s = s.to_lowercase();
+        if s.eq("u8") || s.eq("8") {
+            return Ok(ValueWidth::U8);
+        } else if s.eq("u16") || s.eq("16") {
+            return Ok(ValueWidth::U16);
+        } else if s.eq("u32") || s.eq("32") {
+            return Ok(ValueWidth::U32);
+        } else if s.eq("u64") || s.eq("64") {
+            return Ok(ValueWidth::U64);
+        } else if s.eq("u128") || s.eq("128") {
+            return Ok(ValueWidth::U128);
+        } else if s.eq("undefined") {
+            return Ok(ValueWidth::Undefined);
+        }
+
+        // Otherwise, return err
+        return Err(());
+    }
+}
+impl std::fmt::Display for ValueWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueWidth::Undefined => { write!(f, "undefined") }
+            _                     => { write!(f, "u{}", self.bits()) }
+        }
+    }
+}
+
 /// Defines all binary operators in the AST.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LowBinaryOperator {
@@ -130,6 +361,10 @@ pub enum HighBinaryOperator {
     Multiply,
     /// The divide-operator
     Divide,
+    /// The modulo-operator
+    Modulo,
+    /// The power-operator
+    Power,
 }
 
 impl From<TerminalKind> for HighBinaryOperator {
@@ -137,11 +372,150 @@ impl From<TerminalKind> for HighBinaryOperator {
         match val {
             TerminalKind::MULTIPLY => { HighBinaryOperator::Multiply }
             TerminalKind::DIVIDE   => { HighBinaryOperator::Divide }
+            TerminalKind::MODULO   => { HighBinaryOperator::Modulo }
+            TerminalKind::POWER    => { HighBinaryOperator::Power }
             _                      => { HighBinaryOperator::Undefined }
         }
     }
 }
 
+/// Defines the shift operators in the AST.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShiftOperator {
+    /// Meta value for when no operator is defined
+    Undefined,
+
+    /// The left shift-operator
+    Left,
+    /// The right shift-operator
+    Right,
+}
+
+impl From<TerminalKind> for ShiftOperator {
+    fn from(val: TerminalKind) -> Self {
+        match val {
+            TerminalKind::LSHIFT => { ShiftOperator::Left }
+            TerminalKind::RSHIFT => { ShiftOperator::Right }
+            _                    => { ShiftOperator::Undefined }
+        }
+    }
+}
+
+/// Defines the bitwise binary operators in the AST.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BitwiseBinaryOperator {
+    /// Meta value for when no operator is defined
+    Undefined,
+
+    /// The bitwise and-operator
+    And,
+    /// The bitwise xor-operator
+    Xor,
+    /// The bitwise or-operator
+    Or,
+}
+
+impl From<TerminalKind> for BitwiseBinaryOperator {
+    fn from(val: TerminalKind) -> Self {
+        match val {
+            TerminalKind::AND => { BitwiseBinaryOperator::And }
+            TerminalKind::XOR => { BitwiseBinaryOperator::Xor }
+            TerminalKind::OR  => { BitwiseBinaryOperator::Or }
+            _                 => { BitwiseBinaryOperator::Undefined }
+        }
+    }
+}
+
+/// Defines the comparison operators in the AST.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComparisonOperator {
+    /// Meta value for when no operator is defined
+    Undefined,
+
+    /// The is-equal-operator
+    Equal,
+    /// The not-equal-operator
+    NotEqual,
+    /// The less-than-operator
+    Less,
+    /// The less-than-or-equal-operator
+    LessEqual,
+    /// The greater-than-operator
+    Greater,
+    /// The greater-than-or-equal-operator
+    GreaterEqual,
+    /// The in-range-operator
+    In,
+}
+
+impl From<TerminalKind> for ComparisonOperator {
+    fn from(val: TerminalKind) -> Self {
+        match val {
+            TerminalKind::ISEQUAL      => { ComparisonOperator::Equal }
+            TerminalKind::NOTEQUAL     => { ComparisonOperator::NotEqual }
+            TerminalKind::LESS         => { ComparisonOperator::Less }
+            TerminalKind::LESSEQUAL    => { ComparisonOperator::LessEqual }
+            TerminalKind::GREATER      => { ComparisonOperator::Greater }
+            TerminalKind::GREATEREQUAL => { ComparisonOperator::GreaterEqual }
+            TerminalKind::IN           => { ComparisonOperator::In }
+            _                          => { ComparisonOperator::Undefined }
+        }
+    }
+}
+
+/// Defines the unary operators in the AST.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOperator {
+    /// Meta value for when no operator is defined
+    Undefined,
+
+    /// The bitwise not-operator
+    Not,
+    /// The negation-operator
+    Negate,
+}
+
+impl From<TerminalKind> for UnaryOperator {
+    fn from(val: TerminalKind) -> Self {
+        match val {
+            TerminalKind::NOT   => { UnaryOperator::Not }
+            TerminalKind::MINUS => { UnaryOperator::Negate }
+            _                   => { UnaryOperator::Undefined }
+        }
+    }
+}
+
+/// Defines the (compound) assignment operators in the AST.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssignOperator {
+    /// Meta value for when no operator is defined
+    Undefined,
+
+    /// The plain assign-operator
+    Assign,
+    /// The add-and-assign-operator
+    Plus,
+    /// The subtract-and-assign-operator
+    Minus,
+    /// The multiply-and-assign-operator
+    Multiply,
+    /// The divide-and-assign-operator
+    Divide,
+}
+
+impl From<TerminalKind> for AssignOperator {
+    fn from(val: TerminalKind) -> Self {
+        match val {
+            TerminalKind::EQUALS         => { AssignOperator::Assign }
+            TerminalKind::PLUSEQUALS     => { AssignOperator::Plus }
+            TerminalKind::MINUSEQUALS    => { AssignOperator::Minus }
+            TerminalKind::MULTIPLYEQUALS => { AssignOperator::Multiply }
+            TerminalKind::DIVIDEEQUALS   => { AssignOperator::Divide }
+            _                            => { AssignOperator::Undefined }
+        }
+    }
+}
+
 
 
 
@@ -161,6 +535,16 @@ pub enum ASTNode {
     DelAll { pos1: usize, pos2: usize },
     /// Defines the 'show_vars' node.
     ShowVars { pos1: usize, pos2: usize },
+    /// Defines the 'set' node.
+    Set { setting: String, value: String, pos1: usize, pos2: usize },
+    /// Defines the 'open' node.
+    Open { path: String, pos1: usize, pos2: usize },
+    /// Defines the 'load_elf' node.
+    LoadElf { path: String, pos1: usize, pos2: usize },
+    /// Defines the 'load_pe' node.
+    LoadPe { path: String, pos1: usize, pos2: usize },
+    /// Defines the 'dump' node, with the expressions for the offset and the optional length.
+    Dump { expr: Box<ASTNode>, len: Option<Box<ASTNode>>, pos1: usize, pos2: usize },
     /// Defines the 'clear_hist' node.
     ClearHist { pos1: usize, pos2: usize },
     /// Defines the 'help' node.
@@ -169,7 +553,21 @@ pub enum ASTNode {
     Exit { pos1: usize, pos2: usize },
 
     /// Defines an expression in the AST
-    Expr { override_kind: bool, kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    Expr { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, range: bool, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a comparison-expression in the AST, which is an expression but for the comparisons
+    CmpExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a range-expression in the AST, which is an expression but for the ranges
+    RangeExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a bitwise or-expression in the AST, which is an expression but for the bitwise or
+    BitOrExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a bitwise xor-expression in the AST, which is an expression but for the bitwise xor
+    BitXorExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a bitwise and-expression in the AST, which is an expression but for the bitwise and
+    BitAndExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a shift-expression in the AST, which is an expression but for the shifts
+    ShiftExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a sum in the AST, which is an expression but for the addition and subtraction
+    SumExpr { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
     /// Defines a term in the AST, which is an expression but for operators of higher precedence
     Term { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
     /// Defines a factor in the AST, which is an expression but for operators of more higher precedence
@@ -180,18 +578,40 @@ pub enum ASTNode {
     TinyFactor { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
 
     /// Defines an assignment of an identifier
-    Assign { override_kind: bool, kind: ValueKind, identifier: String, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    Assign { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: AssignOperator, identifier: String, expr: Box<ASTNode>, pos1: usize, pos2: usize },
     /// Defines a binary operator for lower precedence operators in the AST
-    BinOpLow { override_kind: bool, kind: ValueKind, operator: LowBinaryOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
+    BinOpLow { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: LowBinaryOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
     /// Defines a binary operator for higher precedence operators in the AST
-    BinOpHigh { override_kind: bool, kind: ValueKind, operator: HighBinaryOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
+    BinOpHigh { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: HighBinaryOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a binary operator for the shift operators in the AST
+    BinOpShift { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: ShiftOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a binary operator for the bitwise operators in the AST
+    BinOpBitwise { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: BitwiseBinaryOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a binary operator for the comparison operators in the AST
+    BinOpCmp { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: ComparisonOperator, left: Box<ASTNode>, right: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a range ('start..end' or 'start..+length') in the AST
+    Range { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, by_length: bool, start: Box<ASTNode>, end: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a conditional expression ('cond ? a : b') in the AST
+    Ternary { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, cond: Box<ASTNode>, if_true: Box<ASTNode>, if_false: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a unary operator in the AST
+    UnaryOp { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, operator: UnaryOperator, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a cast to another width in the AST
+    Cast { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    /// Defines a bit slice ('value[hi:lo]' or 'value[bit]') in the AST, where the number of bits in the slice is only known once it's computed
+    Slice { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, bits: u32, expr: Box<ASTNode>, hi: Box<ASTNode>, lo: Option<Box<ASTNode>>, pos1: usize, pos2: usize },
+    /// Defines a call to a built-in function in the AST
+    Call { override_kind: bool, kind: ValueKind, signed: bool, width: ValueWidth, identifier: String, args: Vec<ASTNode>, pos1: usize, pos2: usize },
     /// Defines a monary operator in the AST
-    MonOp { kind: ValueKind, expr: Box<ASTNode>, pos1: usize, pos2: usize },
+    MonOp { kind: ValueKind, signed: bool, width: ValueWidth, expr: Box<ASTNode>, pos1: usize, pos2: usize },
     
     /// Defines an identifier in the AST
     Id { identifier: String, pos1: usize, pos2: usize },
     /// Defines a constant in the AST
-    Const { kind: ValueKind, value: u64, pos1: usize, pos2: usize },
+    Const { kind: ValueKind, value: u128, pos1: usize, pos2: usize },
+    /// Defines a character literal in the AST, which is only turned into a value once we know the byte order
+    Chars { bytes: Vec<u8>, pos1: usize, pos2: usize },
+    /// Defines a float constant in the AST, which may only be given to float functions
+    Float { value: f64, pos1: usize, pos2: usize },
 }
 
 impl std::fmt::Debug for ASTNode {
@@ -204,23 +624,51 @@ impl std::fmt::Debug for ASTNode {
             ASTNode::Del{ ref identifier, pos1: _, pos2: _ } => { write!(f, "Del({})", identifier) }
             ASTNode::DelAll{ pos1: _, pos2: _ }              => { write!(f, "DelAll") }
             ASTNode::ShowVars{ pos1: _, pos2: _ }            => { write!(f, "ShowVars") }
+            ASTNode::Set{ ref setting, ref value, pos1: _, pos2: _ } => { write!(f, "Set({} = {})", setting, value) }
+            ASTNode::Open{ ref path, pos1: _, pos2: _ }      => { write!(f, "Open({})", path) }
+            ASTNode::LoadElf{ ref path, pos1: _, pos2: _ }   => { write!(f, "LoadElf({})", path) }
+            ASTNode::LoadPe{ ref path, pos1: _, pos2: _ }    => { write!(f, "LoadPe({})", path) }
+            ASTNode::Dump{ expr, len, pos1: _, pos2: _ }     => { write!(f, "Dump({:?}, {:?})", expr, len) }
             ASTNode::ClearHist{ pos1: _, pos2: _ }            => { write!(f, "ClearHist") }
             ASTNode::Help{ pos1: _, pos2: _ }                => { write!(f, "Help") }
             ASTNode::Exit{ pos1: _, pos2: _ }                => { write!(f, "Exit") }
             
-            ASTNode::Expr{ override_kind: _, kind, expr, pos1: _, pos2: _ } => { write!(f, "Expr<{:?}>({:?})", kind, expr) }
+            ASTNode::Expr{ override_kind: _, kind, signed: _, width: _, range: _, expr, pos1: _, pos2: _ } => { write!(f, "Expr<{:?}>({:?})", kind, expr) }
+            ASTNode::CmpExpr{ kind, expr, pos1: _, pos2: _ }                => { write!(f, "CmpExpr<{:?}>({:?})", kind, expr) }
+            ASTNode::RangeExpr{ kind, expr, pos1: _, pos2: _ }              => { write!(f, "RangeExpr<{:?}>({:?})", kind, expr) }
+            ASTNode::BitOrExpr{ kind, expr, pos1: _, pos2: _ }              => { write!(f, "BitOrExpr<{:?}>({:?})", kind, expr) }
+            ASTNode::BitXorExpr{ kind, expr, pos1: _, pos2: _ }             => { write!(f, "BitXorExpr<{:?}>({:?})", kind, expr) }
+            ASTNode::BitAndExpr{ kind, expr, pos1: _, pos2: _ }             => { write!(f, "BitAndExpr<{:?}>({:?})", kind, expr) }
+            ASTNode::ShiftExpr{ kind, expr, pos1: _, pos2: _ }              => { write!(f, "ShiftExpr<{:?}>({:?})", kind, expr) }
+            ASTNode::SumExpr{ kind, expr, pos1: _, pos2: _ }                => { write!(f, "SumExpr<{:?}>({:?})", kind, expr) }
             ASTNode::Term{ kind, expr, pos1: _, pos2: _ }                   => { write!(f, "Term<{:?}>({:?})", kind, expr) }
             ASTNode::Factor{ kind, expr, pos1: _, pos2: _ }                 => { write!(f, "Factor<{:?}>({:?})", kind, expr) }
             ASTNode::SmallFactor{ kind, expr, pos1: _, pos2: _ }            => { write!(f, "SmallFactor<{:?}>({:?})", kind, expr) }
             ASTNode::TinyFactor{ kind, expr, pos1: _, pos2: _ }             => { write!(f, "TinyFactor<{:?}>({:?})", kind, expr) }
 
-            ASTNode::Assign{ override_kind, kind, ref identifier, expr, pos1: _, pos2: _ }     => { write!(f, "Assign<{} {:?}>({} = {:?})", override_kind, kind, identifier, expr) }
-            ASTNode::BinOpLow{ override_kind, kind, operator, left, right, pos1: _, pos2: _ }  => { write!(f, "BinOpL<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
-            ASTNode::BinOpHigh{ override_kind, kind, operator, left, right, pos1: _, pos2: _ } => { write!(f, "BinOpH<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
-            ASTNode::MonOp{ kind, expr, pos1: _, pos2: _ }                                     => { write!(f, "MonOp<{:?}>({:?})", kind, expr) }
+            ASTNode::Assign{ override_kind, kind, signed: _, width: _, operator, ref identifier, expr, pos1: _, pos2: _ }     => { write!(f, "Assign<{} {:?} {:?}>({} = {:?})", override_kind, kind, operator, identifier, expr) }
+            ASTNode::BinOpLow{ override_kind, kind, signed: _, width: _, operator, left, right, pos1: _, pos2: _ }  => { write!(f, "BinOpL<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
+            ASTNode::BinOpHigh{ override_kind, kind, signed: _, width: _, operator, left, right, pos1: _, pos2: _ } => { write!(f, "BinOpH<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
+            ASTNode::BinOpShift{ override_kind, kind, signed: _, width: _, operator, left, right, pos1: _, pos2: _ }   => { write!(f, "BinOpS<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
+            ASTNode::BinOpBitwise{ override_kind, kind, signed: _, width: _, operator, left, right, pos1: _, pos2: _ } => { write!(f, "BinOpB<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
+            ASTNode::BinOpCmp{ override_kind, kind, signed: _, width: _, operator, left, right, pos1: _, pos2: _ }     => { write!(f, "BinOpC<{} {:?}>({:?} {:?} {:?})", override_kind, kind, left, operator, right) }
+            ASTNode::Range{ override_kind, kind, signed: _, width: _, by_length, start, end, pos1: _, pos2: _ }     => { write!(f, "Range<{} {:?}>({:?} {} {:?})", override_kind, kind, start, if *by_length { "..+" } else { ".." }, end) }
+            ASTNode::Ternary{ override_kind, kind, signed: _, width: _, cond, if_true, if_false, pos1: _, pos2: _ } => { write!(f, "Ternary<{} {:?}>({:?} ? {:?} : {:?})", override_kind, kind, cond, if_true, if_false) }
+            ASTNode::UnaryOp{ override_kind, kind, signed: _, width: _, operator, expr, pos1: _, pos2: _ }          => { write!(f, "UnaryOp<{} {:?}>({:?} {:?})", override_kind, kind, operator, expr) }
+            ASTNode::Cast{ override_kind, kind, signed: _, width, expr, pos1: _, pos2: _ }                    => { write!(f, "Cast<{} {:?}>({}({:?}))", override_kind, kind, width, expr) }
+            ASTNode::Slice{ override_kind, kind, signed: _, width: _, bits: _, expr, hi, lo, pos1: _, pos2: _ } => {
+                match lo {
+                    Some(lo) => { write!(f, "Slice<{} {:?}>({:?}[{:?}:{:?}])", override_kind, kind, expr, hi, lo) }
+                    None     => { write!(f, "Slice<{} {:?}>({:?}[{:?}])", override_kind, kind, expr, hi) }
+                }
+            }
+            ASTNode::Call{ override_kind, kind, signed: _, width: _, identifier, args, pos1: _, pos2: _ }     => { write!(f, "Call<{} {:?}>({}({:?}))", override_kind, kind, identifier, args) }
+            ASTNode::MonOp{ kind, signed: _, width: _, expr, pos1: _, pos2: _ }                                     => { write!(f, "MonOp<{:?}>({:?})", kind, expr) }
 
             ASTNode::Id{ ref identifier, pos1: _, pos2: _ } => {write!(f, "Id({})", identifier) }
             ASTNode::Const{ kind, value, pos1: _, pos2: _ } => { write!(f, "{}<{:?}>", value, kind) }
+            ASTNode::Chars{ bytes, pos1: _, pos2: _ }       => { write!(f, "Chars({:?})", bytes) }
+            ASTNode::Float{ value, pos1: _, pos2: _ }       => { write!(f, "Float({:?})", value) }
         }
     }
 }
@@ -244,23 +692,46 @@ impl Symbol for ASTNode {
             ASTNode::Del{ identifier: _, pos1, pos2 } => { (*pos1, *pos2) }
             ASTNode::DelAll{ pos1, pos2 }             => { (*pos1, *pos2) }
             ASTNode::ShowVars{ pos1, pos2 }           => { (*pos1, *pos2) }
+            ASTNode::Set{ setting: _, value: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::Open{ path: _, pos1, pos2 }      => { (*pos1, *pos2) }
+            ASTNode::LoadElf{ path: _, pos1, pos2 }   => { (*pos1, *pos2) }
+            ASTNode::LoadPe{ path: _, pos1, pos2 }    => { (*pos1, *pos2) }
+            ASTNode::Dump{ expr: _, len: _, pos1, pos2 } => { (*pos1, *pos2) }
             ASTNode::ClearHist{ pos1, pos2 }          => { (*pos1, *pos2) }
             ASTNode::Help{ pos1, pos2 }               => { (*pos1, *pos2) }
             ASTNode::Exit{ pos1, pos2 }               => { (*pos1, *pos2) }
             
-            ASTNode::Expr{ override_kind: _, kind: _, expr: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::CmpExpr{ kind: _, expr: _, pos1, pos2 }                => { (*pos1, *pos2) }
+            ASTNode::RangeExpr{ kind: _, expr: _, pos1, pos2 }              => { (*pos1, *pos2) }
+            ASTNode::BitOrExpr{ kind: _, expr: _, pos1, pos2 }              => { (*pos1, *pos2) }
+            ASTNode::BitXorExpr{ kind: _, expr: _, pos1, pos2 }             => { (*pos1, *pos2) }
+            ASTNode::BitAndExpr{ kind: _, expr: _, pos1, pos2 }             => { (*pos1, *pos2) }
+            ASTNode::ShiftExpr{ kind: _, expr: _, pos1, pos2 }              => { (*pos1, *pos2) }
+            ASTNode::SumExpr{ kind: _, expr: _, pos1, pos2 }                => { (*pos1, *pos2) }
             ASTNode::Term{ kind: _, expr: _, pos1, pos2 }                   => { (*pos1, *pos2) }
             ASTNode::Factor{ kind: _, expr: _, pos1, pos2 }                 => { (*pos1, *pos2) }
             ASTNode::SmallFactor{ kind: _, expr: _, pos1, pos2 }            => { (*pos1, *pos2) }
             ASTNode::TinyFactor{ kind: _, expr: _, pos1, pos2 }             => { (*pos1, *pos2) }
 
-            ASTNode::Assign{ override_kind: _, kind: _, identifier: _, expr: _, pos1, pos2 }            => { (*pos1, *pos2) }
-            ASTNode::BinOpLow{ override_kind: _, kind: _, operator: _, left: _, right: _, pos1, pos2 }  => { (*pos1, *pos2) }
-            ASTNode::BinOpHigh{ override_kind: _, kind: _, operator: _, left: _, right: _, pos1, pos2 } => { (*pos1, *pos2) }
-            ASTNode::MonOp{ kind: _, expr: _, pos1, pos2 }                                              => { (*pos1, *pos2) }
+            ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator: _, identifier: _, expr: _, pos1, pos2 }            => { (*pos1, *pos2) }
+            ASTNode::BinOpLow{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 }  => { (*pos1, *pos2) }
+            ASTNode::BinOpHigh{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::BinOpShift{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 }   => { (*pos1, *pos2) }
+            ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::BinOpCmp{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 }     => { (*pos1, *pos2) }
+            ASTNode::Range{ override_kind: _, kind: _, signed: _, width: _, by_length: _, start: _, end: _, pos1, pos2 }     => { (*pos1, *pos2) }
+            ASTNode::Ternary{ override_kind: _, kind: _, signed: _, width: _, cond: _, if_true: _, if_false: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::UnaryOp{ override_kind: _, kind: _, signed: _, width: _, operator: _, expr: _, pos1, pos2 }            => { (*pos1, *pos2) }
+            ASTNode::Cast{ override_kind: _, kind: _, signed: _, width: _, expr: _, pos1, pos2 }                             => { (*pos1, *pos2) }
+            ASTNode::Slice{ override_kind: _, kind: _, signed: _, width: _, bits: _, expr: _, hi: _, lo: _, pos1, pos2 }     => { (*pos1, *pos2) }
+            ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, identifier: _, args: _, pos1, pos2 }              => { (*pos1, *pos2) }
+            ASTNode::MonOp{ kind: _, signed: _, width: _, expr: _, pos1, pos2 }                                              => { (*pos1, *pos2) }
 
             ASTNode::Id{ identifier: _, pos1, pos2 }        => { (*pos1, *pos2) }
             ASTNode::Const{ kind: _, value: _, pos1, pos2 } => { (*pos1, *pos2) }
+            ASTNode::Chars{ bytes: _, pos1, pos2 }          => { (*pos1, *pos2) }
+            ASTNode::Float{ value: _, pos1, pos2 }          => { (*pos1, *pos2) }
         }
     }
     /// Updates the position of the symbol.
@@ -278,23 +749,46 @@ impl Symbol for ASTNode {
             ASTNode::Del{ identifier: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::DelAll{ ref mut pos1, ref mut pos2 }             => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::ShowVars{ ref mut pos1, ref mut pos2 }           => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Set{ setting: _, value: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Open{ path: _, ref mut pos1, ref mut pos2 }      => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::LoadElf{ path: _, ref mut pos1, ref mut pos2 }   => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::LoadPe{ path: _, ref mut pos1, ref mut pos2 }    => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Dump{ expr: _, len: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::ClearHist{ ref mut pos1, ref mut pos2 }          => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::Help{ ref mut pos1, ref mut pos2 }               => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::Exit{ ref mut pos1, ref mut pos2 }               => { *pos1 = new_pos1; *pos2 = new_pos2; }
 
-            ASTNode::Expr{ override_kind: _, kind: _, expr: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::CmpExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }                => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::RangeExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }              => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BitOrExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }              => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BitXorExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }             => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BitAndExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }             => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::ShiftExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }              => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::SumExpr{ kind: _, expr: _, ref mut pos1, ref mut pos2 }                => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::Term{ kind: _, expr: _, ref mut pos1, ref mut pos2 }                   => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::Factor{ kind: _, expr: _, ref mut pos1, ref mut pos2 }                 => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::SmallFactor{ kind: _, expr: _, ref mut pos1, ref mut pos2 }            => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::TinyFactor{ kind: _, expr: _, ref mut pos1, ref mut pos2 }             => { *pos1 = new_pos1; *pos2 = new_pos2; }
             
-            ASTNode::Assign{ override_kind: _, kind: _, identifier: _, expr: _, ref mut pos1, ref mut pos2 }            => { *pos1 = new_pos1; *pos2 = new_pos2; }
-            ASTNode::BinOpLow{ override_kind: _, kind: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 }  => { *pos1 = new_pos1; *pos2 = new_pos2; }
-            ASTNode::BinOpHigh{ override_kind: _, kind: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
-            ASTNode::MonOp{ kind: _, expr: _, ref mut pos1, ref mut pos2 }                                              => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator: _, identifier: _, expr: _, ref mut pos1, ref mut pos2 }            => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BinOpLow{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 }  => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BinOpHigh{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BinOpShift{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 }   => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::BinOpCmp{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, ref mut pos1, ref mut pos2 }     => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Range{ override_kind: _, kind: _, signed: _, width: _, by_length: _, start: _, end: _, ref mut pos1, ref mut pos2 }     => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Ternary{ override_kind: _, kind: _, signed: _, width: _, cond: _, if_true: _, if_false: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::UnaryOp{ override_kind: _, kind: _, signed: _, width: _, operator: _, expr: _, ref mut pos1, ref mut pos2 }            => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Cast{ override_kind: _, kind: _, signed: _, width: _, expr: _, ref mut pos1, ref mut pos2 }                             => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Slice{ override_kind: _, kind: _, signed: _, width: _, bits: _, expr: _, hi: _, lo: _, ref mut pos1, ref mut pos2 }     => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, identifier: _, args: _, ref mut pos1, ref mut pos2 }              => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::MonOp{ kind: _, signed: _, width: _, expr: _, ref mut pos1, ref mut pos2 }                                              => { *pos1 = new_pos1; *pos2 = new_pos2; }
 
             ASTNode::Id{ identifier: _, ref mut pos1, ref mut pos2 }         => { *pos1 = new_pos1; *pos2 = new_pos2; }
             ASTNode::Const{ kind: _,  value: _, ref mut pos1, ref mut pos2 } => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Chars{ bytes: _, ref mut pos1, ref mut pos2 }           => { *pos1 = new_pos1; *pos2 = new_pos2; }
+            ASTNode::Float{ value: _, ref mut pos1, ref mut pos2 }           => { *pos1 = new_pos1; *pos2 = new_pos2; }
         }
     }
 }
@@ -356,6 +850,30 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("clear_hist");
                         }
+                        TerminalKind::OPEN(path) => {
+                            // Replace with the nonterminal version
+                            stack[i] = Box::new(ASTNode::Open {
+                                path: path.clone(),
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("open");
+                        }
+                        TerminalKind::LOADELF(path) => {
+                            // Replace with the nonterminal version
+                            stack[i] = Box::new(ASTNode::LoadElf {
+                                path: path.clone(),
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("load_elf");
+                        }
+                        TerminalKind::LOADPE(path) => {
+                            // Replace with the nonterminal version
+                            stack[i] = Box::new(ASTNode::LoadPe {
+                                path: path.clone(),
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("load_pe");
+                        }
                         TerminalKind::HELP => {
                             // Replace with the nonterminal version
                             stack[i] = Box::new(ASTNode::Help {
@@ -377,6 +895,12 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             state = ParserState::RBracket;
                             continue;
                         }
+                        TerminalKind::RSQUARE => {
+                            // Start consuming the slice
+                            last_token = token;
+                            state = ParserState::RSquare;
+                            continue;
+                        }
 
                         TerminalKind::ID(_) => {
                             // Go to the id state
@@ -385,6 +909,27 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             continue;
                         }
                         TerminalKind::DEC(val) => {
+                            // If it's the value of a set-command, join them in a Set node instead
+                            if i >= 2 && stack[i - 2].is_terminal() && stack[i - 1].is_terminal() {
+                                let set_token = stack[i - 2].as_any().downcast_ref::<Token>().unwrap();
+                                let id_token  = stack[i - 1].as_any().downcast_ref::<Token>().unwrap();
+                                if let (TerminalKind::SET, TerminalKind::ID(setting)) = (&set_token.kind, &id_token.kind) {
+                                    let ns = Box::new(ASTNode::Set{
+                                        setting: setting.clone(),
+                                        value: val.to_string(),
+                                        pos1: set_token.pos1, pos2: token.pos2
+                                    });
+
+                                    // Replace on the stack
+                                    stack.remove(stack.len() - 1);
+                                    stack.remove(stack.len() - 1);
+                                    stack[i - 2] = ns;
+
+                                    // Done
+                                    return String::from("set");
+                                }
+                            }
+
                             // Replace on the stack with a const
                             stack[i] = Box::new(ASTNode::Const{
                                 kind: ValueKind::Decimal,
@@ -393,6 +938,35 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("const_dec");
                         }
+                        TerminalKind::TOU8 |
+                        TerminalKind::TOU16 |
+                        TerminalKind::TOU32 |
+                        TerminalKind::TOU64 |
+                        TerminalKind::TOU128 => {
+                            // Only reduce if it's the value of a set-command (i.e., 'set width u32'); otherwise, wait for the cast's brackets
+                            if i >= 2 && stack[i - 2].is_terminal() && stack[i - 1].is_terminal() {
+                                let set_token = stack[i - 2].as_any().downcast_ref::<Token>().unwrap();
+                                let id_token  = stack[i - 1].as_any().downcast_ref::<Token>().unwrap();
+                                if let (TerminalKind::SET, TerminalKind::ID(setting)) = (&set_token.kind, &id_token.kind) {
+                                    let ns = Box::new(ASTNode::Set{
+                                        setting: setting.clone(),
+                                        value: ValueWidth::from(token.kind.clone()).to_string(),
+                                        pos1: set_token.pos1, pos2: token.pos2
+                                    });
+
+                                    // Replace on the stack
+                                    stack.remove(stack.len() - 1);
+                                    stack.remove(stack.len() - 1);
+                                    stack[i - 2] = ns;
+
+                                    // Done
+                                    return String::from("set");
+                                }
+                            }
+
+                            // Otherwise, nothing to be done yet
+                            return String::new();
+                        }
                         TerminalKind::HEX(val) => {
                             // Replace on the stack with a const
                             stack[i] = Box::new(ASTNode::Const{
@@ -411,6 +985,40 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("const_bin");
                         }
+                        TerminalKind::OCT(val) => {
+                            // Replace on the stack with a const
+                            stack[i] = Box::new(ASTNode::Const{
+                                kind: ValueKind::Octal,
+                                value: *val,
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("const_oct");
+                        }
+                        TerminalKind::RADIX(_, val) => {
+                            // Replace on the stack with a const
+                            stack[i] = Box::new(ASTNode::Const{
+                                kind: ValueKind::from(token.kind.clone()),
+                                value: *val,
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("const_radix");
+                        }
+                        TerminalKind::CHARS(ref bytes) => {
+                            // Replace on the stack with a character literal
+                            stack[i] = Box::new(ASTNode::Chars{
+                                bytes: bytes.clone(),
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("chars");
+                        }
+                        TerminalKind::FLOAT(value) => {
+                            // Replace on the stack with a float constant
+                            stack[i] = Box::new(ASTNode::Float{
+                                value: *value,
+                                pos1: token.pos1, pos2: token.pos2
+                            });
+                            return String::from("float");
+                        }
 
                         // Ignore the rest
                         _ => { return String::new(); }
@@ -446,6 +1054,46 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("cmd_showvars");
                         }
+                        ASTNode::Set{ setting: _, value: _, pos1, pos2 } => {
+                            // Cast to a command
+                            stack[i] = Box::new(ASTNode::Cmd{
+                                cmd: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("cmd_set");
+                        }
+                        ASTNode::Open{ path: _, pos1, pos2 } => {
+                            // Cast to a command
+                            stack[i] = Box::new(ASTNode::Cmd{
+                                cmd: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("cmd_open");
+                        }
+                        ASTNode::LoadElf{ path: _, pos1, pos2 } => {
+                            // Cast to a command
+                            stack[i] = Box::new(ASTNode::Cmd{
+                                cmd: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("cmd_load_elf");
+                        }
+                        ASTNode::LoadPe{ path: _, pos1, pos2 } => {
+                            // Cast to a command
+                            stack[i] = Box::new(ASTNode::Cmd{
+                                cmd: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("cmd_load_pe");
+                        }
+                        ASTNode::Dump{ expr: _, len: _, pos1, pos2 } => {
+                            // Cast to a command
+                            stack[i] = Box::new(ASTNode::Cmd{
+                                cmd: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("cmd_dump");
+                        }
                         ASTNode::ClearHist{ pos1, pos2 } => {
                             // Cast to a command
                             stack[i] = Box::new(ASTNode::Cmd{
@@ -471,17 +1119,98 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             return String::from("cmd_exit");
                         }
 
-                        ASTNode::BinOpLow{ override_kind: _, kind: _, operator: _, left: _, right: _, pos1, pos2 } => {
-                            // Cast to a strongexpression
+                        ASTNode::Ternary{ override_kind: _, kind: _, signed: _, width: _, cond: _, if_true: _, if_false: _, pos1, pos2 } => {
+                            // Cast to an expression
                             stack[i] = Box::new(ASTNode::Expr{
                                 override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                range: false,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("expr_ternary");
+                        }
+                        ASTNode::BinOpCmp{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 } => {
+                            // Cast to a comparison-expression
+                            stack[i] = Box::new(ASTNode::CmpExpr{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("cmpexpr_binopcmp");
+                        }
+                        ASTNode::Range{ override_kind: _, kind: _, signed: _, width: _, by_length: _, start: _, end: _, pos1, pos2 } => {
+                            // Cast to a range-expression
+                            stack[i] = Box::new(ASTNode::RangeExpr{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("rangeexpr_range");
+                        }
+                        ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed: _, width: _, operator, left: _, right: _, pos1, pos2 } => {
+                            // Cast to the expression matching the operator's precedence
+                            match operator {
+                                BitwiseBinaryOperator::Or => {
+                                    stack[i] = Box::new(ASTNode::BitOrExpr{
+                                        kind: ValueKind::Undefined,
+                                        expr: Box::new(node.clone()),
+                                        pos1: *pos1, pos2: *pos2
+                                    });
+                                    return String::from("bitorexpr_binopbitwise");
+                                }
+                                BitwiseBinaryOperator::Xor => {
+                                    stack[i] = Box::new(ASTNode::BitXorExpr{
+                                        kind: ValueKind::Undefined,
+                                        expr: Box::new(node.clone()),
+                                        pos1: *pos1, pos2: *pos2
+                                    });
+                                    return String::from("bitxorexpr_binopbitwise");
+                                }
+                                BitwiseBinaryOperator::And => {
+                                    stack[i] = Box::new(ASTNode::BitAndExpr{
+                                        kind: ValueKind::Undefined,
+                                        expr: Box::new(node.clone()),
+                                        pos1: *pos1, pos2: *pos2
+                                    });
+                                    return String::from("bitandexpr_binopbitwise");
+                                }
+                                BitwiseBinaryOperator::Undefined => {
+                                    panic!("Encountered an undefined binopbitwise at pos {}: this should never happen!", pos1);
+                                }
+                            }
+                        }
+                        ASTNode::BinOpShift{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 } => {
+                            // Cast to a shift-expression
+                            stack[i] = Box::new(ASTNode::ShiftExpr{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("shiftexpr_binopshift");
+                        }
+                        ASTNode::BinOpLow{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1, pos2 } => {
+                            // Cast to a sum
+                            stack[i] = Box::new(ASTNode::SumExpr{
                                 kind: ValueKind::Undefined,
                                 expr: Box::new(node.clone()),
                                 pos1: *pos1, pos2: *pos2
                             });
-                            return String::from("strongexpr_binoplow");
+                            return String::from("sumexpr_binoplow");
                         }
-                        ASTNode::BinOpHigh{ override_kind: _, kind: _, operator: _, left: _, right: _, pos1, pos2 } => {
+                        ASTNode::BinOpHigh{ override_kind: _, kind: _, signed: _, width: _, operator, left: _, right: _, pos1, pos2 } => {
+                            // The power binds stronger than the rest, so cast that one to a smallfactor
+                            if *operator == HighBinaryOperator::Power {
+                                stack[i] = Box::new(ASTNode::SmallFactor{
+                                    kind: ValueKind::Undefined,
+                                    expr: Box::new(node.clone()),
+                                    pos1: *pos1, pos2: *pos2
+                                });
+                                return String::from("smallfactor_binophigh");
+                            }
+
                             // Cast to an expression
                             stack[i] = Box::new(ASTNode::Term{
                                 kind: ValueKind::Undefined,
@@ -490,7 +1219,7 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("term_binophigh");
                         }
-                        ASTNode::MonOp{ kind: _, expr: _, pos1, pos2 } => {
+                        ASTNode::MonOp{ kind: _, signed: _, width: _, expr: _, pos1, pos2 } => {
                             // Cast to a factor
                             stack[i] = Box::new(ASTNode::Factor{
                                 kind: ValueKind::Undefined,
@@ -499,7 +1228,16 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("factor_monop");
                         }
-                        ASTNode::Assign{ override_kind: _, kind: _, identifier: _, expr: _, pos1, pos2 } => {
+                        ASTNode::UnaryOp{ override_kind: _, kind: _, signed: _, width: _, operator: _, expr: _, pos1, pos2 } => {
+                            // Cast to a factor
+                            stack[i] = Box::new(ASTNode::Factor{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("factor_unop");
+                        }
+                        ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator: _, identifier: _, expr: _, pos1, pos2 } => {
                             // Cast it to a smallfactor
                             stack[i] = Box::new(ASTNode::SmallFactor{
                                 kind: ValueKind::Undefined,
@@ -527,14 +1265,101 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             });
                             return String::from("tinyfactor_const");
                         }
-
-                        ASTNode::Expr{ override_kind: _, kind: _, expr: _, pos1: _, pos2: _ } => {
-                            // Go to the start of possibly a binoplow
-                            last_node = node;
-                            state = ParserState::Expr;
-                            continue;
+                        ASTNode::Chars{ bytes: _, pos1, pos2 } => {
+                            // Cast to a smallfactor
+                            stack[i] = Box::new(ASTNode::TinyFactor{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("tinyfactor_chars");
                         }
-                        ASTNode::Term{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                        ASTNode::Float{ value: _, pos1, pos2 } => {
+                            // Cast to a smallfactor
+                            stack[i] = Box::new(ASTNode::TinyFactor{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("tinyfactor_float");
+                        }
+                        ASTNode::Cast{ override_kind: _, kind: _, signed: _, width: _, expr: _, pos1, pos2 } => {
+                            // Cast to a smallfactor
+                            stack[i] = Box::new(ASTNode::TinyFactor{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("tinyfactor_cast");
+                        }
+                        ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, identifier: _, args: _, pos1, pos2 } => {
+                            // Cast to a smallfactor
+                            stack[i] = Box::new(ASTNode::TinyFactor{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("tinyfactor_call");
+                        }
+                        ASTNode::Slice{ override_kind: _, kind: _, signed: _, width: _, bits: _, expr: _, hi: _, lo: _, pos1, pos2 } => {
+                            // Cast to a smallfactor
+                            stack[i] = Box::new(ASTNode::TinyFactor{
+                                kind: ValueKind::Undefined,
+                                expr: Box::new(node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+                            return String::from("tinyfactor_slice");
+                        }
+
+                        ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a binoplow
+                            last_node = node;
+                            state = ParserState::Expr;
+                            continue;
+                        }
+                        ASTNode::CmpExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly an expression
+                            last_node = node;
+                            state = ParserState::CmpExpr;
+                            continue;
+                        }
+                        ASTNode::RangeExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a comparison
+                            last_node = node;
+                            state = ParserState::RangeExpr;
+                            continue;
+                        }
+                        ASTNode::BitOrExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a range
+                            last_node = node;
+                            state = ParserState::BitOrExpr;
+                            continue;
+                        }
+                        ASTNode::BitXorExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a bitwise or
+                            last_node = node;
+                            state = ParserState::BitXorExpr;
+                            continue;
+                        }
+                        ASTNode::BitAndExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a bitwise xor
+                            last_node = node;
+                            state = ParserState::BitAndExpr;
+                            continue;
+                        }
+                        ASTNode::ShiftExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a bitwise and
+                            last_node = node;
+                            state = ParserState::ShiftExpr;
+                            continue;
+                        }
+                        ASTNode::SumExpr{ kind: _, expr: _, pos1: _, pos2: _ } => {
+                            // Go to the start of possibly a binopshift
+                            last_node = node;
+                            state = ParserState::SumExpr;
+                            continue;
+                        }
+                        ASTNode::Term{ kind: _, expr: _, pos1: _, pos2: _ } => {
                             // Go to the start of possibly a binoplow
                             last_node = node;
                             state = ParserState::Term;
@@ -556,6 +1381,13 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             return String::from("factor_smallfactor");
                         }
                         ASTNode::TinyFactor{ kind: _, expr: _, pos1, pos2 } => {
+                            // If the lookahead is a power or a slice, let the tinyfactor be
+                            match lookahead.kind {
+                                TerminalKind::POWER |
+                                TerminalKind::LSQUARE => { return String::new(); }
+                                _ => {}
+                            }
+
                             // Cast to a smallfactor
                             stack[i] = Box::new(ASTNode::SmallFactor{
                                 kind: ValueKind::Undefined,
@@ -609,14 +1441,44 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                                 // Done
                                 return String::from("del");
                             }
+                            TerminalKind::SET => {
+                                // This ID is the name of the setting; wait for its value
+                                return String::new();
+                            }
+                            TerminalKind::ID(ref setting) => {
+                                // If this is preceded by a set, then this ID is the value of the setting
+                                if i > 0 && stack[i - 1].is_terminal() {
+                                    let set_token = stack[i - 1].as_any().downcast_ref::<Token>().unwrap();
+                                    if let TerminalKind::SET = set_token.kind {
+                                        let ns = Box::new(ASTNode::Set{
+                                            setting: setting.clone(),
+                                            value: id.clone(),
+                                            pos1: set_token.pos1, pos2: last_token.pos2
+                                        });
+
+                                        // Replace on the stack
+                                        stack.remove(stack.len() - 1);
+                                        stack.remove(stack.len() - 1);
+                                        stack[i - 1] = ns;
+
+                                        // Done
+                                        return String::from("set");
+                                    }
+                                }
+                            }
                             _ => {}
                         }
                     }
                 }
 
-                // Do not do it if there's an EQUALS coming up
+                // Do not do it if there's an (compound) EQUALS or a function call coming up
                 match lookahead.kind {
-                    TerminalKind::EQUALS => {
+                    TerminalKind::EQUALS |
+                    TerminalKind::PLUSEQUALS |
+                    TerminalKind::MINUSEQUALS |
+                    TerminalKind::MULTIPLYEQUALS |
+                    TerminalKind::DIVIDEEQUALS |
+                    TerminalKind::LBRACKET => {
                         // Skip replacing
                         return String::new();
                     }
@@ -635,6 +1497,16 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
 
 
             ParserState::Expr => {
+                // If the lookahead is a question mark, this is the condition of a conditional; let the expression be
+                match lookahead.kind {
+                    TerminalKind::QUESTION => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
                 // Get the next symbol
                 if i == 0 { return String::new(); }
                 i -= 1;
@@ -648,12 +1520,18 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                     // Switch on its kind
                     match token.kind {
                         // Could be a monary op
-                        TerminalKind::TODEC |
-                        TerminalKind::TOHEX |
-                        TerminalKind::TOBIN => {
+                        TerminalKind::TODEC  |
+                        TerminalKind::TOSDEC |
+                        TerminalKind::TOHEX  |
+                        TerminalKind::TOBIN  |
+                        TerminalKind::TOOCT  |
+                        TerminalKind::TOBASE(_) |
+                        TerminalKind::TOFLOAT => {
                             // It is; generate the new symbol
                             let ns = Box::new(ASTNode::MonOp{
                                 kind: ValueKind::from(token.kind.clone()),
+                                signed: token.kind == TerminalKind::TOSDEC,
+                                width: ValueWidth::Undefined,
                                 expr: Box::new(last_node.clone()),
                                 pos1: token.pos1, pos2: last_node.pos().1 }
                             );
@@ -666,21 +1544,84 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             return String::from("monop");
                         }
 
-                        // Could be an assign
-                        TerminalKind::EQUALS => {
+                        // Could be an (compound) assign
+                        TerminalKind::EQUALS |
+                        TerminalKind::PLUSEQUALS |
+                        TerminalKind::MINUSEQUALS |
+                        TerminalKind::MULTIPLYEQUALS |
+                        TerminalKind::DIVIDEEQUALS => {
                             // It is; go to the equals state
                             last_token = token;
                             state = ParserState::Expr_Equals;
                             continue;
                         }
 
+                        // Could be the last branch of a conditional
+                        TerminalKind::COLON => {
+                            // It is; go to the colon state
+                            last_token = token;
+                            state = ParserState::Expr_Colon;
+                            continue;
+                        }
+
+                        // Could be the offset of a dump, but only once the statement ends (as a length may follow)
+                        TerminalKind::DUMP => {
+                            match lookahead.kind {
+                                TerminalKind::Eos |
+                                TerminalKind::SEMICOLON => {}
+                                _ => { return String::new(); }
+                            }
+
+                            // Generate the new symbol
+                            let ns = Box::new(ASTNode::Dump{
+                                expr: Box::new(last_node.clone()),
+                                len: None,
+                                pos1: token.pos1, pos2: last_node.pos().1
+                            });
+
+                            // Insert it in the stack instead of the top two ones
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Done
+                            return String::from("dump");
+                        }
+
                         // Ignore the rest
                         _ => { return String::new(); }
                     }
 
                 } else {
-                    // Ignore all nonterminals too
-                    return String::new();
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Could be the offset of a dump with this expression as its length, once the statement ends
+                    match (node, &lookahead.kind) {
+                        (ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ }, TerminalKind::Eos) |
+                        (ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ }, TerminalKind::SEMICOLON) => {
+                            if i == 0 || !stack[i - 1].is_terminal() { return String::new(); }
+                            let token = stack[i - 1].as_any().downcast_ref::<Token>().unwrap();
+                            if token.kind != TerminalKind::DUMP { return String::new(); }
+
+                            // Generate the new symbol
+                            let ns = Box::new(ASTNode::Dump{
+                                expr: Box::new(node.clone()),
+                                len: Some(Box::new(last_node.clone())),
+                                pos1: token.pos1, pos2: last_node.pos().1
+                            });
+
+                            // Insert it in the stack instead of the top three ones
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i - 1] = ns;
+
+                            // Done
+                            return String::from("dump");
+                        }
+
+                        // Ignore all other nonterminals
+                        _ => { return String::new(); }
+                    }
                 }
             }
 
@@ -703,48 +1644,839 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                                 let ns = Box::new(ASTNode::Assign{
                                     override_kind: false,
                                     kind: ValueKind::Undefined,
+                                    signed: false,
+                                    width: ValueWidth::Undefined,
+                                    operator: AssignOperator::from(last_token.kind.clone()),
                                     identifier: id.clone(),
                                     expr: Box::new(last_node.clone()),
                                     pos1: token.pos1, pos2: token.pos2
                                 });
 
-                                // Replace it on the stack
-                                stack.remove(stack.len() - 1);
-                                stack.remove(stack.len() - 1);
-                                stack[i] = ns;
+                                // Replace it on the stack
+                                stack.remove(stack.len() - 1);
+                                stack.remove(stack.len() - 1);
+                                stack[i] = ns;
+
+                                // Done
+                                return String::from("assign");
+                            }
+
+                            // Used a keyword
+                            TerminalKind::TODEC |
+                            TerminalKind::TOSDEC |
+                            TerminalKind::TOHEX |
+                            TerminalKind::TOBIN |
+                            TerminalKind::TOOCT |
+                            TerminalKind::TOBASE(_) |
+                            TerminalKind::TOFLOAT |
+                            TerminalKind::TOU8 |
+                            TerminalKind::TOU16 |
+                            TerminalKind::TOU32 |
+                            TerminalKind::TOU64 |
+                            TerminalKind::TOU128 |
+                            TerminalKind::DEL |
+                            TerminalKind::DELALL |
+                            TerminalKind::SHOWVARS |
+                            TerminalKind::SET |
+                            TerminalKind::DUMP |
+                            TerminalKind::CLEARHIST |
+                            TerminalKind::HELP |
+                            TerminalKind::EXIT => {
+                                // Tell the user what happened
+                                eprintln!("   {}: Expected identifier, got keyword {}.", token.pos().0, &input[token.pos1 - 1..token.pos2]);
+                                stack.remove(stack.len() - 1);
+                                stack.remove(stack.len() - 1);
+                                return String::from("error");
+                            }
+
+                            _ => {}
+                        }
+
+                    }
+                }
+
+                // Not what we expected!
+                eprintln!("   {}: Missing identifier before assign.", last_token.pos().0);
+                stack.remove(stack.len() - 1);
+                stack.remove(stack.len() - 1);
+                return String::from("error");
+            }
+
+
+
+            ParserState::Expr_Colon => {
+                // Get the next symbol, which should be the first branch
+                if i == 0 || stack[i - 1].is_terminal() {
+                    eprintln!("   {}: Missing value before ':'.", last_token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+                }
+                i -= 1;
+                let if_true = stack[i].as_any().downcast_ref::<ASTNode>().unwrap();
+                match if_true {
+                    ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {}
+
+                    // The rest is probably a malformed node that is treated during the post-analysis
+                    _ => { return String::new(); }
+                }
+
+                // That branch should be preceded by a question mark (unless it's the upper bit of a slice instead)
+                let mut has_question = false;
+                if i > 0 && stack[i - 1].is_terminal() {
+                    match stack[i - 1].as_any().downcast_ref::<Token>().unwrap().kind {
+                        TerminalKind::QUESTION => { has_question = true; }
+                        TerminalKind::LSQUARE  => { return String::new(); }
+                        _ => {}
+                    }
+                }
+                if !has_question {
+                    eprintln!("   {}: Missing '?' before ':'.", if_true.pos().0);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+                }
+                i -= 1;
+
+                // And the question mark should be preceded by the condition
+                if i == 0 || stack[i - 1].is_terminal() {
+                    eprintln!("   {}: Missing condition before '?'.", stack[i].pos().0);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+                }
+                i -= 1;
+                let cond = stack[i].as_any().downcast_ref::<ASTNode>().unwrap();
+                match cond {
+                    ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1, pos2: _ } => {
+                        // Construct the conditional!
+                        let ns = Box::new(ASTNode::Ternary{
+                            override_kind: false,
+                            kind: ValueKind::Undefined,
+                            signed: false,
+                            width: ValueWidth::Undefined,
+                            cond: Box::new(cond.clone()),
+                            if_true: Box::new(if_true.clone()),
+                            if_false: Box::new(last_node.clone()),
+                            pos1: *pos1, pos2: last_node.pos().1
+                        });
+
+                        // Replace on the stack
+                        stack.truncate(i + 1);
+                        stack[i] = ns;
+
+                        // Done
+                        return String::from("ternary");
+                    }
+
+                    // For the rest, throw an error
+                    _ => {
+                        eprintln!("   {}: Incompatible symbol '{}' before '?'.", cond.pos().0, &input[cond.pos().0 - 1..cond.pos().1]);
+                        stack.remove(stack.len() - 1);
+                        stack.remove(stack.len() - 1);
+                        return String::from("error");
+                    }
+                }
+            }
+
+
+
+            ParserState::CmpExpr => {
+                // If the lookahead is a comparison, let the expression be
+                match lookahead.kind {
+                    TerminalKind::ISEQUAL |
+                    TerminalKind::NOTEQUAL |
+                    TerminalKind::LESS |
+                    TerminalKind::LESSEQUAL |
+                    TerminalKind::GREATER |
+                    TerminalKind::GREATEREQUAL |
+                    TerminalKind::IN => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Replace the original comparison-expression by an expression
+                let ns = Box::new(ASTNode::Expr{
+                    override_kind: false,
+                    kind: ValueKind::Undefined,
+                    signed: false,
+                    width: ValueWidth::Undefined,
+                    range: false,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("expr_cmpexpr");
+            }
+
+
+
+            ParserState::RangeExpr => {
+                // If the lookahead is a range sign, let the expression be
+                match lookahead.kind {
+                    TerminalKind::DOTDOT |
+                    TerminalKind::DOTDOTPLUS => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Get the next symbol
+                if i > 0 {
+                    i -= 1;
+                    let s = &stack[i];
+
+                    // Switch on terminal VS nonterminal
+                    if s.is_terminal() {
+                        // Downcast
+                        let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                        // Switch on its kind
+                        match token.kind {
+                            TerminalKind::ISEQUAL |
+                            TerminalKind::NOTEQUAL |
+                            TerminalKind::LESS |
+                            TerminalKind::LESSEQUAL |
+                            TerminalKind::GREATER |
+                            TerminalKind::GREATEREQUAL |
+                            TerminalKind::IN => {
+                                // Go to the last step of the comparison
+                                last_token = token;
+                                state = ParserState::RangeExpr_Cmp;
+                                continue;
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Replace the original range-expression by a comparison-expression
+                let ns = Box::new(ASTNode::CmpExpr{
+                    kind: ValueKind::Undefined,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("cmpexpr_rangeexpr");
+            }
+
+            ParserState::RangeExpr_Cmp => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before comparison.", token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Switch on the type
+                    match node {
+                        ASTNode::CmpExpr{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the comparison!
+                            let ns = Box::new(ASTNode::BinOpCmp{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                operator: ComparisonOperator::from(last_token.kind.clone()),
+                                left: Box::new(node.clone()),
+                                right: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("binopcmp");
+                        }
+
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before comparison.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                    }
+                }
+            }
+
+
+
+            ParserState::BitOrExpr => {
+                // If the lookahead is an or, let the expression be
+                match lookahead.kind {
+                    TerminalKind::OR => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Get the next symbol
+                if i > 0 {
+                    i -= 1;
+                    let s = &stack[i];
+
+                    // Switch on terminal VS nonterminal
+                    if s.is_terminal() {
+                        // Downcast
+                        let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                        // Switch on its kind
+                        match token.kind {
+                            TerminalKind::DOTDOT |
+                            TerminalKind::DOTDOTPLUS => {
+                                // Go to the last step of the range
+                                last_token = token;
+                                state = ParserState::BitOrExpr_Range;
+                                continue;
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Replace the original bitwise or-expression by a range-expression
+                let ns = Box::new(ASTNode::RangeExpr{
+                    kind: ValueKind::Undefined,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("rangeexpr_bitorexpr");
+            }
+
+            ParserState::BitOrExpr_Range => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before range.", token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Switch on the type
+                    match node {
+                        ASTNode::RangeExpr{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the range!
+                            let ns = Box::new(ASTNode::Range{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                by_length: last_token.kind == TerminalKind::DOTDOTPLUS,
+                                start: Box::new(node.clone()),
+                                end: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("range");
+                        }
+
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before range.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                    }
+                }
+            }
+
+
+
+            ParserState::BitXorExpr => {
+                // If the lookahead is a xor, let the expression be
+                match lookahead.kind {
+                    TerminalKind::XOR => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Get the next symbol
+                if i > 0 {
+                    i -= 1;
+                    let s = &stack[i];
+
+                    // Switch on terminal VS nonterminal
+                    if s.is_terminal() {
+                        // Downcast
+                        let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                        // Switch on its kind
+                        match token.kind {
+                            TerminalKind::OR => {
+                                // Go to the last step of the bitwise or
+                                last_token = token;
+                                state = ParserState::BitXorExpr_Or;
+                                continue;
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Replace the original bitwise xor-expression by a bitwise or-expression
+                let ns = Box::new(ASTNode::BitOrExpr{
+                    kind: ValueKind::Undefined,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("bitorexpr_bitxorexpr");
+            }
+
+            ParserState::BitXorExpr_Or => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before bitwise or.", token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Switch on the type
+                    match node {
+                        ASTNode::BitOrExpr{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the bitwise or!
+                            let ns = Box::new(ASTNode::BinOpBitwise{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                operator: BitwiseBinaryOperator::Or,
+                                left: Box::new(node.clone()),
+                                right: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("binopbitwise_or");
+                        }
+
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before bitwise or.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                    }
+                }
+            }
+
+
+
+            ParserState::BitAndExpr => {
+                // If the lookahead is an and, let the expression be
+                match lookahead.kind {
+                    TerminalKind::AND => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Get the next symbol
+                if i > 0 {
+                    i -= 1;
+                    let s = &stack[i];
+
+                    // Switch on terminal VS nonterminal
+                    if s.is_terminal() {
+                        // Downcast
+                        let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                        // Switch on its kind
+                        match token.kind {
+                            TerminalKind::XOR => {
+                                // Go to the last step of the bitwise xor
+                                last_token = token;
+                                state = ParserState::BitAndExpr_Xor;
+                                continue;
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Replace the original bitwise and-expression by a bitwise xor-expression
+                let ns = Box::new(ASTNode::BitXorExpr{
+                    kind: ValueKind::Undefined,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("bitxorexpr_bitandexpr");
+            }
+
+            ParserState::BitAndExpr_Xor => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before bitwise xor.", token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Switch on the type
+                    match node {
+                        ASTNode::BitXorExpr{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the bitwise xor!
+                            let ns = Box::new(ASTNode::BinOpBitwise{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                operator: BitwiseBinaryOperator::Xor,
+                                left: Box::new(node.clone()),
+                                right: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("binopbitwise_xor");
+                        }
+
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before bitwise xor.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                    }
+                }
+            }
+
+
+
+            ParserState::ShiftExpr => {
+                // If the lookahead is a shift, let the shift-expression be
+                match lookahead.kind {
+                    TerminalKind::LSHIFT |
+                    TerminalKind::RSHIFT => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Get the next symbol
+                if i > 0 {
+                    i -= 1;
+                    let s = &stack[i];
+
+                    // Switch on terminal VS nonterminal
+                    if s.is_terminal() {
+                        // Downcast
+                        let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                        // Switch on its kind
+                        match token.kind {
+                            TerminalKind::AND => {
+                                // Go to the last step of the bitwise and
+                                last_token = token;
+                                state = ParserState::ShiftExpr_And;
+                                continue;
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Replace the original shift-expression by a bitwise and-expression
+                let ns = Box::new(ASTNode::BitAndExpr{
+                    kind: ValueKind::Undefined,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("bitandexpr_shiftexpr");
+            }
+
+            ParserState::ShiftExpr_And => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before bitwise and.", token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Switch on the type
+                    match node {
+                        ASTNode::BitAndExpr{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the bitwise and!
+                            let ns = Box::new(ASTNode::BinOpBitwise{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                operator: BitwiseBinaryOperator::And,
+                                left: Box::new(node.clone()),
+                                right: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("binopbitwise_and");
+                        }
+
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before bitwise and.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                    }
+                }
+            }
+
+
+
+            ParserState::SumExpr => {
+                // If the lookahead is a plus or a minus, let the sum be
+                match lookahead.kind {
+                    TerminalKind::PLUS |
+                    TerminalKind::MINUS => {
+                        // Skip replacing
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+
+                // Get the next symbol
+                if i > 0 {
+                    i -= 1;
+                    let s = &stack[i];
+
+                    // Switch on terminal VS nonterminal
+                    if s.is_terminal() {
+                        // Downcast
+                        let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                        // Switch on its kind
+                        match token.kind {
+                            TerminalKind::LSHIFT |
+                            TerminalKind::RSHIFT => {
+                                // Go to the last step of the binopshift
+                                last_token = token;
+                                state = ParserState::SumExpr_Shift;
+                                continue;
+                            }
+
+                            _ => {}
+                        }
+                    }
+                }
+
+                // Replace the original sum by a shift-expression
+                let ns = Box::new(ASTNode::ShiftExpr{
+                    kind: ValueKind::Undefined,
+                    expr: Box::new(last_node.clone()),
+                    pos1: last_node.pos().0, pos2: last_node.pos().1
+                });
+
+                // Replace with the top one on the stack
+                let i2 = stack.len() - 1;
+                stack[i2] = ns;
+
+                // Done
+                return String::from("shiftexpr_sumexpr");
+            }
+
+            ParserState::SumExpr_Shift => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Get the operator
+                let op = ShiftOperator::from(last_token.kind.clone());
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before {}.", token.pos1, if op == ShiftOperator::Left { "left shift" } else { "right shift" });
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
 
-                                // Done
-                                return String::from("assign");
-                            }
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
 
-                            // Used a keyword
-                            TerminalKind::TODEC |
-                            TerminalKind::TOHEX |
-                            TerminalKind::TOBIN |
-                            TerminalKind::DEL |
-                            TerminalKind::DELALL |
-                            TerminalKind::SHOWVARS |
-                            TerminalKind::CLEARHIST |
-                            TerminalKind::HELP |
-                            TerminalKind::EXIT => {
-                                // Tell the user what happened
-                                eprintln!("   {}: Expected identifier, got keyword {}.", token.pos().0, &input[token.pos1 - 1..token.pos2]);
-                                stack.remove(stack.len() - 1);
-                                stack.remove(stack.len() - 1);
-                                return String::from("error");
-                            }
+                    // Switch on the type
+                    match node {
+                        ASTNode::ShiftExpr{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the binopshift!
+                            let ns = Box::new(ASTNode::BinOpShift{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                operator: op,
+                                left: Box::new(node.clone()),
+                                right: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
 
-                            _ => {}
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("binopshift");
                         }
 
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before {}.", node.pos().0, &input[node.pos().0 - 1..node.pos().1], if op == ShiftOperator::Left { "left shift" } else { "right shift" });
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
                     }
                 }
-
-                // Not what we expected!
-                eprintln!("   {}: Missing identifier before assign.", last_token.pos().0);
-                stack.remove(stack.len() - 1);
-                stack.remove(stack.len() - 1);
-                return String::from("error");
             }
 
 
@@ -753,7 +2485,8 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                 // If the lookahead is a multiply or a divide, let the term be
                 match lookahead.kind {
                     TerminalKind::MULTIPLY |
-                    TerminalKind::DIVIDE => {
+                    TerminalKind::DIVIDE |
+                    TerminalKind::MODULO => {
                         // Skip replacing
                         return String::new();
                     }
@@ -786,9 +2519,8 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                     }
                 }
 
-                // Replace the original term by an expression
-                let ns = Box::new(ASTNode::Expr{
-                    override_kind: false,
+                // Replace the original term by a sum
+                let ns = Box::new(ASTNode::SumExpr{
                     kind: ValueKind::Undefined,
                     expr: Box::new(last_node.clone()),
                     pos1: last_node.pos().0, pos2: last_node.pos().1
@@ -799,7 +2531,7 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                 stack[i2] = ns;
 
                 // Done
-                return String::from("expr_term");
+                return String::from("sumexpr_term");
             }
 
             ParserState::Term_PlusORMinus => {
@@ -828,11 +2560,13 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
 
                     // Switch on the type
                     match node {
-                        ASTNode::Expr{ override_kind: _, kind: _, expr: _, pos1, pos2 } => {
+                        ASTNode::SumExpr{ kind: _, expr: _, pos1, pos2 } => {
                             // Construct the binoplow!
                             let ns = Box::new(ASTNode::BinOpLow{
                                 override_kind: false,
                                 kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
                                 operator: op,
                                 left: Box::new(node.clone()),
                                 right: Box::new(last_node.clone()),
@@ -875,12 +2609,62 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                         // Switch on its kind
                         match token.kind {
                             TerminalKind::MULTIPLY |
-                            TerminalKind::DIVIDE => {
+                            TerminalKind::DIVIDE |
+                            TerminalKind::MODULO => {
                                 // Go to the last step of the binophigh
                                 last_token = token;
-                                state = ParserState::Factor_MultiplyORDivide;
+                                state = ParserState::Factor_MultiplyORDivideORModulo;
                                 continue;
                             }
+                            TerminalKind::POWER => {
+                                // Go to the last step of the power
+                                last_token = token;
+                                state = ParserState::Factor_Power;
+                                continue;
+                            }
+
+                            // Could be a unary op
+                            TerminalKind::NOT => {
+                                // It is; generate the new symbol
+                                let ns = Box::new(ASTNode::UnaryOp{
+                                    override_kind: false,
+                                    kind: ValueKind::Undefined,
+                                    signed: false,
+                                    width: ValueWidth::Undefined,
+                                    operator: UnaryOperator::from(token.kind.clone()),
+                                    expr: Box::new(last_node.clone()),
+                                    pos1: token.pos1, pos2: last_node.pos().1
+                                });
+
+                                // Insert it in the stack instead of the top two ones
+                                stack.remove(stack.len() - 1);
+                                stack[i] = ns;
+
+                                // Done
+                                return String::from("unop");
+                            }
+                            // Could be a negation, but only if there is no lefthand-side value (otherwise, it's a subtraction)
+                            TerminalKind::MINUS => {
+                                if i == 0 || stack[i - 1].is_terminal() {
+                                    // It is; generate the new symbol
+                                    let ns = Box::new(ASTNode::UnaryOp{
+                                        override_kind: false,
+                                        kind: ValueKind::Undefined,
+                                        signed: false,
+                                        width: ValueWidth::Undefined,
+                                        operator: UnaryOperator::from(token.kind.clone()),
+                                        expr: Box::new(last_node.clone()),
+                                        pos1: token.pos1, pos2: last_node.pos().1
+                                    });
+
+                                    // Insert it in the stack instead of the top two ones
+                                    stack.remove(stack.len() - 1);
+                                    stack[i] = ns;
+
+                                    // Done
+                                    return String::from("unop");
+                                }
+                            }
 
                             _ => {}
                         }
@@ -902,7 +2686,7 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                 return String::from("term_factor");
             }
 
-            ParserState::Factor_MultiplyORDivide => {
+            ParserState::Factor_MultiplyORDivideORModulo => {
                 // Get the next symbol
                 if i == 0 { return String::new(); }
                 i -= 1;
@@ -910,6 +2694,11 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
 
                 // Get the operator
                 let op = HighBinaryOperator::from(last_token.kind.clone());
+                let op_name = match op {
+                    HighBinaryOperator::Multiply => "multiplication",
+                    HighBinaryOperator::Divide   => "division",
+                    _                            => "modulo",
+                };
 
                 // Switch on terminal VS nonterminal
                 if s.is_terminal() {
@@ -917,7 +2706,7 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                     let token = s.as_any().downcast_ref::<Token>().unwrap();
 
                     // Show that this isn't what we mean
-                    eprintln!("   {}: Missing value before {}.", token.pos1, if op == HighBinaryOperator::Multiply { "multiplication" } else { "division" });
+                    eprintln!("   {}: Missing value before {}.", token.pos1, op_name);
                     stack.remove(stack.len() - 1);
                     stack.remove(stack.len() - 1);
                     return String::from("error");
@@ -933,6 +2722,8 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             let ns = Box::new(ASTNode::BinOpHigh{
                                 override_kind: false,
                                 kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
                                 operator: op,
                                 left: Box::new(node.clone()),
                                 right: Box::new(last_node.clone()),
@@ -950,7 +2741,63 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                         
                         // For the rest, throw an error too
                         _ => {
-                            eprintln!("   {}: Incompatible symbol '{}' before {}.", node.pos().0, &input[node.pos().0 - 1..node.pos().1], if op == HighBinaryOperator::Multiply { "multiplication" } else { "division" });
+                            eprintln!("   {}: Incompatible symbol '{}' before {}.", node.pos().0, &input[node.pos().0 - 1..node.pos().1], op_name);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                    }
+                }
+            }
+
+            ParserState::Factor_Power => {
+                // Get the next symbol
+                if i == 0 { return String::new(); }
+                i -= 1;
+                let s = &stack[i];
+
+                // Switch on terminal VS nonterminal
+                if s.is_terminal() {
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // Show that this isn't what we mean
+                    eprintln!("   {}: Missing value before power.", token.pos1);
+                    stack.remove(stack.len() - 1);
+                    stack.remove(stack.len() - 1);
+                    return String::from("error");
+
+                } else {
+                    // Downcast
+                    let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
+
+                    // Switch on the type
+                    match node {
+                        ASTNode::TinyFactor{ kind: _, expr: _, pos1, pos2 } => {
+                            // Construct the binophigh, which is right-associative since the righthand-side is a factor
+                            let ns = Box::new(ASTNode::BinOpHigh{
+                                override_kind: false,
+                                kind: ValueKind::Undefined,
+                                signed: false,
+                                width: ValueWidth::Undefined,
+                                operator: HighBinaryOperator::Power,
+                                left: Box::new(node.clone()),
+                                right: Box::new(last_node.clone()),
+                                pos1: *pos1, pos2: *pos2
+                            });
+
+                            // Replace on the stack
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            stack[i] = ns;
+
+                            // Return!
+                            return String::from("binophigh_power");
+                        }
+                        
+                        // For the rest, throw an error too
+                        _ => {
+                            eprintln!("   {}: Incompatible symbol '{}' before power.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
                             stack.remove(stack.len() - 1);
                             stack.remove(stack.len() - 1);
                             return String::from("error");
@@ -969,7 +2816,35 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
 
                 // Switch on terminal VS nonterminal
                 if s.is_terminal() {
-                    // Simply ignore; any bracket errors are treated during the post-analysis
+                    // Downcast
+                    let token = s.as_any().downcast_ref::<Token>().unwrap();
+
+                    // If it's a left bracket preceded by an identifier, it's a call without arguments
+                    if let TerminalKind::LBRACKET = token.kind {
+                        if i > 0 && stack[i - 1].is_terminal() {
+                            let id_token = stack[i - 1].as_any().downcast_ref::<Token>().unwrap();
+                            if let TerminalKind::ID(ref identifier) = id_token.kind {
+                                let ns = Box::new(ASTNode::Call{
+                                    override_kind: false,
+                                    kind: ValueKind::Undefined,
+                                    signed: false,
+                                    width: ValueWidth::Undefined,
+                                    identifier: identifier.clone(),
+                                    args: Vec::new(),
+                                    pos1: id_token.pos1,
+                                    pos2: last_token.pos2
+                                });
+                                stack.remove(stack.len() - 1);
+                                stack.remove(stack.len() - 1);
+                                stack[i - 1] = ns;
+
+                                // Done
+                                return String::from("call");
+                            }
+                        }
+                    }
+
+                    // Simply ignore the rest; any bracket errors are treated during the post-analysis
                     return String::new();
 
                 } else {
@@ -978,7 +2853,7 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
 
                     // Switch on its kind
                     match node {
-                        ASTNode::Expr{ override_kind: _, kind:_, expr: _, pos1: _, pos2: _ } => {
+                        ASTNode::Expr{ override_kind: _, kind:_, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {
                             // Store it, and keep parsing!
                             last_node = node;
                             state = ParserState::RBracket_Expr;
@@ -1008,8 +2883,59 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                     // Switch on its kind
                     match token.kind {
                         TerminalKind::LBRACKET => {
-                            // Simply join it in a smallfactor
-                            let ns = Box::new(ASTNode::SmallFactor{
+                            // If the bracket is preceded by a width, it's a cast instead; and if it's preceded by an identifier, it's a call
+                            if i > 0 && stack[i - 1].is_terminal() {
+                                let prev_token = stack[i - 1].as_any().downcast_ref::<Token>().unwrap();
+                                match prev_token.kind {
+                                    TerminalKind::TOU8  |
+                                    TerminalKind::TOU16 |
+                                    TerminalKind::TOU32 |
+                                    TerminalKind::TOU64 |
+                                    TerminalKind::TOU128 => {
+                                        // Join them in a cast
+                                        let ns = Box::new(ASTNode::Cast{
+                                            override_kind: false,
+                                            kind: ValueKind::Undefined,
+                                            signed: false,
+                                            width: ValueWidth::from(prev_token.kind.clone()),
+                                            expr: Box::new(last_node.clone()),
+                                            pos1: prev_token.pos1,
+                                            pos2: last_token.pos2
+                                        });
+                                        stack.remove(stack.len() - 1);
+                                        stack.remove(stack.len() - 1);
+                                        stack.remove(stack.len() - 1);
+                                        stack[i - 1] = ns;
+
+                                        // Done
+                                        return String::from("cast");
+                                    }
+                                    TerminalKind::ID(ref identifier) => {
+                                        // Join them in a call with a single argument
+                                        let ns = Box::new(ASTNode::Call{
+                                            override_kind: false,
+                                            kind: ValueKind::Undefined,
+                                            signed: false,
+                                            width: ValueWidth::Undefined,
+                                            identifier: identifier.clone(),
+                                            args: vec![last_node.clone()],
+                                            pos1: prev_token.pos1,
+                                            pos2: last_token.pos2
+                                        });
+                                        stack.remove(stack.len() - 1);
+                                        stack.remove(stack.len() - 1);
+                                        stack.remove(stack.len() - 1);
+                                        stack[i - 1] = ns;
+
+                                        // Done
+                                        return String::from("call");
+                                    }
+                                    _ => {}
+                                }
+                            }
+
+                            // Simply join it in a tinyfactor
+                            let ns = Box::new(ASTNode::TinyFactor{
                                 kind: ValueKind::Undefined,
                                 expr: Box::new(last_node.clone()),
                                 pos1: token.pos1,
@@ -1022,6 +2948,55 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                             // Done
                             return String::from("brackets");
                         }
+                        TerminalKind::COMMA => {
+                            // It's the last argument of a call; collect the others up to the left bracket
+                            let mut args: Vec<ASTNode> = vec![last_node.clone()];
+                            let mut j = i;
+                            loop {
+                                // The comma should be preceded by an expression
+                                if j == 0 || stack[j - 1].is_terminal() { return String::new(); }
+                                let arg = stack[j - 1].as_any().downcast_ref::<ASTNode>().unwrap();
+                                if let ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } = arg {
+                                    args.insert(0, arg.clone());
+                                } else {
+                                    return String::new();
+                                }
+
+                                // That expression should be preceded by another comma or the left bracket
+                                if j < 2 || !stack[j - 2].is_terminal() { return String::new(); }
+                                let sep_token = stack[j - 2].as_any().downcast_ref::<Token>().unwrap();
+                                j -= 2;
+                                match sep_token.kind {
+                                    TerminalKind::COMMA    => { continue; }
+                                    TerminalKind::LBRACKET => { break; }
+                                    _                      => { return String::new(); }
+                                }
+                            }
+
+                            // Finally, the left bracket should be preceded by the function's name
+                            if j == 0 || !stack[j - 1].is_terminal() { return String::new(); }
+                            let id_token = stack[j - 1].as_any().downcast_ref::<Token>().unwrap();
+                            if let TerminalKind::ID(ref identifier) = id_token.kind {
+                                let ns = Box::new(ASTNode::Call{
+                                    override_kind: false,
+                                    kind: ValueKind::Undefined,
+                                    signed: false,
+                                    width: ValueWidth::Undefined,
+                                    identifier: identifier.clone(),
+                                    args: args,
+                                    pos1: id_token.pos1,
+                                    pos2: last_token.pos2
+                                });
+                                stack.truncate(j);
+                                stack[j - 1] = ns;
+
+                                // Done
+                                return String::from("call");
+                            }
+
+                            // Otherwise, it's a stray comma that is treated during the post-analysis
+                            return String::new();
+                        }
 
                         // Simply ignore the rest; any bracket errors are treated during the post-analysis
                         _ => { return String::new(); }
@@ -1032,6 +3007,100 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
                     return String::new();
                 }
             }
+
+
+
+            ParserState::RSquare => {
+                // Get the first symbol, which should be the (lower) bit of the slice
+                if i == 0 || stack[i - 1].is_terminal() {
+                    eprintln!("   {}: Missing bit index before ']'.", last_token.pos1);
+                    stack.remove(stack.len() - 1);
+                    if i > 0 { stack.remove(stack.len() - 1); }
+                    return String::from("error");
+                }
+                i -= 1;
+                let node = stack[i].as_any().downcast_ref::<ASTNode>().unwrap();
+                match node {
+                    ASTNode::Expr{ override_kind: _, kind:_, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {
+                        // Store it, and keep parsing!
+                        last_node = node;
+                        state = ParserState::RSquare_Expr;
+                        continue;
+                    }
+
+                    // The rest is probably a malformed node
+                    _ => {
+                        // Simply ignore; any bracket errors are treated during the post-analysis
+                        return String::new();
+                    }
+                }
+            }
+
+            ParserState::RSquare_Expr => {
+                // Get the next symbol, which is either the left square bracket or the colon that separates the bits
+                if i == 0 || !stack[i - 1].is_terminal() { return String::new(); }
+                i -= 1;
+                let token = stack[i].as_any().downcast_ref::<Token>().unwrap();
+                let (hi, lo) = match token.kind {
+                    TerminalKind::LSQUARE => {
+                        // It's a single bit
+                        (last_node, None)
+                    }
+                    TerminalKind::COLON => {
+                        // It's a range of bits, so it should be preceded by the upper bit
+                        if i == 0 || stack[i - 1].is_terminal() {
+                            eprintln!("   {}: Missing bit index before ':'.", token.pos1);
+                            stack.remove(stack.len() - 1);
+                            stack.remove(stack.len() - 1);
+                            return String::from("error");
+                        }
+                        i -= 1;
+                        let hi = stack[i].as_any().downcast_ref::<ASTNode>().unwrap();
+                        if let ASTNode::Expr{ override_kind: _, kind:_, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } = hi {} else { return String::new(); }
+
+                        // Which in turn should be preceded by the left square bracket
+                        if i == 0 || !stack[i - 1].is_terminal() { return String::new(); }
+                        i -= 1;
+                        if let TerminalKind::LSQUARE = stack[i].as_any().downcast_ref::<Token>().unwrap().kind {} else { return String::new(); }
+                        (hi, Some(Box::new(last_node.clone())))
+                    }
+
+                    // Simply ignore the rest; any bracket errors are treated during the post-analysis
+                    _ => { return String::new(); }
+                };
+
+                // Finally, the left square bracket should be preceded by the value to slice
+                let value = if i > 0 && !stack[i - 1].is_terminal() { stack[i - 1].as_any().downcast_ref::<ASTNode>().unwrap() } else { &ASTNode::Undefined };
+                match value {
+                    ASTNode::TinyFactor{ kind: _, expr: _, pos1, pos2: _ } => {
+                        // Join them in a slice
+                        let ns = Box::new(ASTNode::Slice{
+                            override_kind: false,
+                            kind: ValueKind::Undefined,
+                            signed: false,
+                            width: ValueWidth::Undefined,
+                            bits: 0,
+                            expr: Box::new(value.clone()),
+                            hi: Box::new(hi.clone()),
+                            lo: lo,
+                            pos1: *pos1,
+                            pos2: last_token.pos2
+                        });
+                        stack.truncate(i);
+                        stack[i - 1] = ns;
+
+                        // Done
+                        return String::from("slice");
+                    }
+
+                    // For the rest, throw an error
+                    _ => {
+                        eprintln!("   {}: Missing value before '['.", stack[i].pos().0);
+                        stack.truncate(i);
+                        return String::from("error");
+                    }
+                }
+            }
         }
 
         // Should never get here!
@@ -1044,14 +3113,14 @@ fn reduce(input: &str, stack: &mut Vec<Box<dyn Symbol>>, lookahead: &Token) -> S
 
 
 /***** LIBRARY FUNCTIONS *****/
-/// Parses the given string into an AST of the math it represents.
+/// Parses the given string into ASTs of the math it represents, one for every statement (separated by semicolons).
 /// 
 /// **Arguments**
 ///  * `input`: The string to parse.
 /// 
 /// **Returns**  
-/// The first node in the AST, or None if an error occurred (which will already have been printed).
-pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
+/// The first node in the AST of each statement (which may be none if the string is empty), or None if an error occurred (which will already have been printed).
+pub fn parse<'a>(input: &'a str) -> Option<Vec<ASTNode>> {
     // Prepare the tokenizer to use for input
     let mut tokenizer = Tokenizer::new(input);
     let mut lookahead = tokenizer.get();
@@ -1088,6 +3157,11 @@ pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
                     eprintln!("   {}: Encountered unknown token '{}'.", lookahead.pos1, *err);
                     errored = true;
                 }
+                TerminalKind::Error(ref err) => {
+                    // Encountered a malformed token; show why and try to get more
+                    eprintln!("   {}: {}", lookahead.pos1, *err);
+                    errored = true;
+                }
                 _ => {
                     // It's a legal token; push it to the stack
                     stack.push(Box::new(lookahead));
@@ -1099,8 +3173,10 @@ pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
         }
     }
 
-    // Next, analyse the remaining stack
+    // Next, analyse the remaining stack, which should contain one symbol per statement
+    let mut statements: Vec<ASTNode> = Vec::new();
     let mut is_cmd = false;
+    let mut in_statement = false;
     for i in 0..stack.len() {
         let s = &stack[i];
 
@@ -1111,6 +3187,11 @@ pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
 
             // Switch on its kind
             match token.kind {
+                TerminalKind::SEMICOLON => {
+                    // The end of a statement
+                    in_statement = false;
+                    continue;
+                }
                 TerminalKind::LBRACKET => {
                     eprintln!("   {}: Unmatched left bracket.", token.pos1);
                     errored = true;
@@ -1121,6 +3202,21 @@ pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
                     errored = true;
                     continue;
                 }
+                TerminalKind::LSQUARE => {
+                    eprintln!("   {}: Unmatched left square bracket.", token.pos1);
+                    errored = true;
+                    continue;
+                }
+                TerminalKind::RSQUARE => {
+                    eprintln!("   {}: Unmatched right square bracket.", token.pos1);
+                    errored = true;
+                    continue;
+                }
+                TerminalKind::DUMP => {
+                    eprintln!("   {}: Missing offset after 'dump'.", token.pos1);
+                    errored = true;
+                    continue;
+                }
 
                 _ => {
                     eprintln!("   {}: Unexpected symbol '{}'.", token.pos1, &input[token.pos1 - 1..token.pos2]);
@@ -1133,24 +3229,34 @@ pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
             // Downcast
             let node = s.as_any().downcast_ref::<ASTNode>().unwrap();
 
+            // If the statement already has a symbol, then this one is too much
+            if in_statement {
+                eprintln!("   {}: Unexpected symbol '{}'.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                errored = true;
+                continue;
+            }
+            in_statement = true;
+
             // Switch on its kind
             match node {
-                ASTNode::Expr{ override_kind: _, kind: _, expr: _, pos1: _, pos2: _ } => {
+                ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {
                     // Compain if in command mode
                     if is_cmd {
                         eprintln!("   {}: Cannot give an expression ('{}') in between a command.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
                         errored = true;
                         continue;
                     }
+                    statements.push(node.clone());
                 }
                 ASTNode::Cmd{ cmd: _, pos1: _, pos2:_  } => {
                     // Only change modes if it's the first
-                    if i == 0 { is_cmd = true; }
+                    if statements.is_empty() { is_cmd = true; }
                     else {
-                        eprintln!("   {}: Cannot give a command ('{}') in between an expression.", node.pos().0, &input[node.pos().0 - 1..node.pos().1]);
+                        eprintln!("   {}: Cannot give a command ('{}') in between {}.", node.pos().0, &input[node.pos().0 - 1..node.pos().1], if is_cmd { "another command" } else { "an expression" });
                         errored = true;
                         continue;
                     }
+                    statements.push(node.clone());
                 }
 
                 _ => {
@@ -1161,16 +3267,12 @@ pub fn parse<'a>(input: &'a str) -> Option<ASTNode> {
             }
         }
     }
-    for i in 1..stack.len() {
-        eprintln!("   {}: Unexpected symbol '{}'.", stack[i].pos().0, &input[stack[i].pos().0 - 1..stack[i].pos().1]);
-        errored = true;
-    }
 
     // If an error occurred, stop
     if errored { return None; }
 
-    // Done; return the single node!
-    return Some(stack[0].as_any().downcast_ref::<ASTNode>().unwrap().clone());
+    // Done; return the statements!
+    return Some(statements);
 }
 
 
@@ -1206,10 +3308,131 @@ pub fn get_command(ast: &ASTNode) -> Option<&ASTNode> {
 /// The type of the root node as a ValueKind. While this can also be 'Undefined', this really shouldn't happen.
 pub fn get_kind(ast: &ASTNode) -> ValueKind {
     // Check if the root node is an expression
-    if let ASTNode::Expr{ override_kind: _, kind, expr: _, pos1: _, pos2: _ } = ast {
+    if let ASTNode::Expr{ override_kind: _, kind, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } = ast {
         return *kind;
     }
 
     // Otherwise, return blank
     return ValueKind::Undefined;
 }
+
+/// Given an already processed and parsed AST, returns the width of the topmost expression.
+/// 
+/// **Arguments**
+///  * `ast`: The AST to get the root node width from.
+/// 
+/// **Returns**  
+/// The width of the root node as a ValueWidth. Returns 'Undefined' if the root is not an expression.
+pub fn get_width(ast: &ASTNode) -> ValueWidth {
+    // Check if the root node is an expression
+    if let ASTNode::Expr{ override_kind: _, kind: _, signed: _, width, range: _, expr: _, pos1: _, pos2: _ } = ast {
+        return *width;
+    }
+
+    // Otherwise, return blank
+    return ValueWidth::Undefined;
+}
+
+/// Given an already processed and parsed AST, returns whether the topmost expression is signed.
+/// 
+/// **Arguments**
+///  * `ast`: The AST to get the root node signedness from.
+/// 
+/// **Returns**  
+/// Whether the root node should be interpreted as a signed value. Returns false if the root is not an expression.
+pub fn get_signed(ast: &ASTNode) -> bool {
+    // Check if the root node is an expression
+    if let ASTNode::Expr{ override_kind: _, kind: _, signed, width: _, range: _, expr: _, pos1: _, pos2: _ } = ast {
+        return *signed;
+    }
+
+    // Otherwise, return false
+    return false;
+}
+
+/// Given an already processed and parsed AST, returns whether the topmost expression is a range.
+/// 
+/// **Arguments**
+///  * `ast`: The AST to get the root node's range-ness from.
+/// 
+/// **Returns**  
+/// Whether the root node is a range instead of a single value. Returns false if the root is not an expression.
+pub fn get_range(ast: &ASTNode) -> bool {
+    // Check if the root node is an expression
+    if let ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range, expr: _, pos1: _, pos2: _ } = ast {
+        return *range;
+    }
+
+    // Otherwise, return false
+    return false;
+}
+
+/// Given an already processed and computed AST, returns the number of bits in the slice that the topmost expression results in.
+/// 
+/// **Arguments**
+///  * `ast`: The AST to get the root node's slice from.
+/// 
+/// **Returns**  
+/// The number of bits if the root node is a slice (possibly converted to another representation), or None otherwise.
+pub fn get_slice_bits(ast: &ASTNode) -> Option<u32> {
+    // Look through the expressions and conversions on top of the slice
+    match ast {
+        ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr, pos1: _, pos2: _ } |
+        ASTNode::MonOp{ kind: _, signed: _, width: _, expr, pos1: _, pos2: _ } => {
+            return get_slice_bits(expr);
+        }
+        ASTNode::Slice{ override_kind: _, kind: _, signed: _, width: _, bits, expr: _, hi: _, lo: _, pos1: _, pos2: _ } => {
+            return Some(*bits);
+        }
+
+        // Otherwise, it's no slice
+        _ => { return None; }
+    }
+}
+
+/// Returns the value of the given node if it's a (possibly negated) float constant.
+/// 
+/// **Arguments**
+///  * `ast`: The (trimmed) node to check, which is typically the argument of a float function.
+/// 
+/// **Returns**  
+/// The value of the float constant, or None if the node is something else.
+pub fn get_float(ast: &ASTNode) -> Option<f64> {
+    // Look through the expressions and negations on top of the float
+    match ast {
+        ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr, pos1: _, pos2: _ } => {
+            return get_float(expr);
+        }
+        ASTNode::UnaryOp{ override_kind: _, kind: _, signed: _, width: _, operator: UnaryOperator::Negate, expr, pos1: _, pos2: _ } => {
+            return get_float(expr).map(|value| -value);
+        }
+        ASTNode::Float{ value, pos1: _, pos2: _ } => {
+            return Some(*value);
+        }
+
+        // Otherwise, it's no float
+        _ => { return None; }
+    }
+}
+
+/// Returns the value of the given node if it's an integer constant, as it was written (i.e., before it's fitted in any width).
+/// 
+/// **Arguments**
+///  * `ast`: The (trimmed) node to check, which is typically the operand of a negation.
+/// 
+/// **Returns**  
+/// The value of the constant, or None if the node is something else.
+pub fn get_const(ast: &ASTNode) -> Option<u128> {
+    // Look through the expressions on top of the constant
+    match ast {
+        ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr, pos1: _, pos2: _ } => {
+            return get_const(expr);
+        }
+        ASTNode::Const{ kind: _, value, pos1: _, pos2: _ } => {
+            return Some(*value);
+        }
+
+        // Otherwise, it's no constant
+        _ => { return None; }
+    }
+}
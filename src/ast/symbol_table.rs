@@ -13,6 +13,60 @@
 **/
 
 pub use crate::ast::parser::ValueKind;
+pub use crate::ast::parser::ValueWidth;
 
-/// Type shortcut for the SymbolTable.
-pub type SymbolTable = std::collections::HashMap<String, (ValueKind, u64)>;
+
+/***** LIBRARY STRUCTS *****/
+/// Defines a single variable in the SymbolTable, i.e., a value and its type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Variable {
+    /// The kind of the value, which determines how it's shown.
+    pub kind   : ValueKind,
+    /// The (raw) value itself, or the start if the variable is a range.
+    pub value  : u128,
+    /// Whether the value is signed.
+    pub signed : bool,
+    /// The width of the value.
+    pub width  : ValueWidth,
+    /// The end of the range if the variable is a range, or None otherwise.
+    pub end    : Option<u128>,
+}
+
+impl Variable {
+    /// Constructor for the Variable.
+    /// 
+    /// **Arguments**
+    ///  * `kind`: The kind of the value.
+    ///  * `value`: The raw value, or the start if the variable is a range.
+    ///  * `signed`: Whether the value is signed.
+    ///  * `width`: The width of the value.
+    ///  * `end`: The end of the range if the variable is a range, or None otherwise.
+    /// 
+    /// **Returns**  
+    /// A new Variable with the given value and type.
+    pub fn new(kind: ValueKind, value: u128, signed: bool, width: ValueWidth, end: Option<u128>) -> Variable {
+        return Variable {
+            kind,
+            value,
+            signed,
+            width,
+            end,
+        };
+    }
+
+    /// Constructor for a Variable that has no value yet, such as 'ans' before anything has been computed.
+    /// 
+    /// **Returns**  
+    /// A new Variable with an undefined kind and width.
+    pub fn undefined() -> Variable {
+        return Variable::new(ValueKind::Undefined, 0, false, ValueWidth::Undefined, None);
+    }
+}
+
+
+
+
+
+/***** LIBRARY TYPES *****/
+/// Type shortcut for the SymbolTable, which maps identifiers to their variables.
+pub type SymbolTable = std::collections::HashMap<String, Variable>;
@@ -29,21 +29,56 @@ pub enum TerminalKind {
     ID(String),
 
     /// A decimal value.
-    DEC(u64),
+    DEC(u128),
     /// A hexadecimal value.
-    HEX(u64),
+    HEX(u128),
     /// A binary value.
-    BIN(u64),
+    BIN(u128),
+    /// An octal value.
+    OCT(u128),
+    /// A character (or short string) literal, as its raw bytes.
+    CHARS(Vec<u8>),
+    /// A value in an arbitrary radix, as its radix and its value.
+    RADIX(u32, u128),
+    /// A floating-point value, which may only be given to float functions.
+    FLOAT(f64),
 
     /// The to-decimal token
     TODEC,
+    /// The to-signed-decimal token
+    TOSDEC,
     /// The to-hexadecimal token
     TOHEX,
     /// The to-binary token
     TOBIN,
+    /// The to-octal token
+    TOOCT,
+    /// The to-arbitrary-radix token, with the radix to convert to
+    TOBASE(u32),
+    /// The to-float token
+    TOFLOAT,
+
+    /// The to-8-bit-width token
+    TOU8,
+    /// The to-16-bit-width token
+    TOU16,
+    /// The to-32-bit-width token
+    TOU32,
+    /// The to-64-bit-width token
+    TOU64,
+    /// The to-128-bit-width token
+    TOU128,
 
     // The equals-sign
     EQUALS,
+    /// The plus-equals-sign
+    PLUSEQUALS,
+    /// The minus-equals-sign
+    MINUSEQUALS,
+    /// The multiply-equals-sign
+    MULTIPLYEQUALS,
+    /// The divide-equals-sign
+    DIVIDEEQUALS,
 
     /// The plus-sign.
     PLUS,
@@ -53,11 +88,62 @@ pub enum TerminalKind {
     MULTIPLY,
     /// The divide-sign.
     DIVIDE,
+    /// The modulo-sign.
+    MODULO,
+    /// The power-sign.
+    POWER,
+
+    /// The left shift-sign.
+    LSHIFT,
+    /// The right shift-sign.
+    RSHIFT,
+
+    /// The bitwise and-sign.
+    AND,
+    /// The bitwise or-sign.
+    OR,
+    /// The bitwise xor-sign.
+    XOR,
+    /// The bitwise not-sign.
+    NOT,
+
+    /// The is-equal-sign.
+    ISEQUAL,
+    /// The not-equal-sign.
+    NOTEQUAL,
+    /// The less-than-sign.
+    LESS,
+    /// The less-than-or-equal-sign.
+    LESSEQUAL,
+    /// The greater-than-sign.
+    GREATER,
+    /// The greater-than-or-equal-sign.
+    GREATEREQUAL,
+    /// The in-keyword that checks if a value lies in a range.
+    IN,
+
+    /// The range-sign that separates a start and an end.
+    DOTDOT,
+    /// The range-sign that separates a start and a length.
+    DOTDOTPLUS,
+
+    /// The question mark that starts the branches of a conditional
+    QUESTION,
+    /// The colon that separates the branches of a conditional
+    COLON,
 
     /// The left bracket
     LBRACKET,
     /// The right bracket
     RBRACKET,
+    /// The left square bracket that starts a bit slice
+    LSQUARE,
+    /// The right square bracket that ends a bit slice
+    RSQUARE,
+    /// The comma that separates arguments
+    COMMA,
+    /// The semicolon that separates statements
+    SEMICOLON,
 
     /// The Del token
     DEL,
@@ -65,6 +151,16 @@ pub enum TerminalKind {
     DELALL,
     /// The ShowVars token
     SHOWVARS,
+    /// The Set token
+    SET,
+    /// The Open token, with the path of the file to open
+    OPEN(String),
+    /// The LoadElf token, with the path of the ELF file to load
+    LOADELF(String),
+    /// The LoadPe token, with the path of the PE file to load
+    LOADPE(String),
+    /// The Dump token
+    DUMP,
     /// The ClearHist token
     CLEARHIST,
     /// The Help token
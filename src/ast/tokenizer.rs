@@ -30,7 +30,14 @@ macro_rules! is_whitespace {
 /// Checks if the given 'char' equals a separator: a whitespace, '\0', or some way we know a new token starts.
 macro_rules! is_separator {
     ($c:expr) => {
-        (is_whitespace!($c) || $c.eq("\0") || $c.eq("+") || $c.eq("-") || $c.eq("*") || $c.eq("/") || $c.eq("(") || $c.eq(")"))
+        (is_whitespace!($c) || $c.eq("\0") || $c.eq("+") || $c.eq("-") || $c.eq("*") || $c.eq("/") || $c.eq("%") || $c.eq("<") || $c.eq(">") || $c.eq("&") || $c.eq("|") || $c.eq("^") || $c.eq("~") || $c.eq("(") || $c.eq(")") || $c.eq(",") || $c.eq("=") || $c.eq("!") || $c.eq("?") || $c.eq(":") || $c.eq(";") || $c.eq(".") || $c.eq("[") || $c.eq("]"))
+    };
+}
+
+/// Checks if the given 'char' equals a separator between digits (e.g., '0xFFFF_FFFF' or '1'000').
+macro_rules! is_digit_separator {
+    ($c:expr) => {
+        ($c.eq("_") || $c.eq("'"))
     };
 }
 
@@ -67,6 +74,14 @@ macro_rules! is_hex {
     };
 }
 
+/// Checks if the given 'char' equals an octal digit.
+macro_rules! is_octal {
+    ($c:expr) => {
+        ($c.eq("0") || $c.eq("1") || $c.eq("2") || $c.eq("3") ||
+         $c.eq("4") || $c.eq("5") || $c.eq("6") || $c.eq("7"))
+    };
+}
+
 /// Checks if the given 'char' equals a binary digit.
 macro_rules! is_binary {
     ($c:expr) => {
@@ -85,6 +100,31 @@ enum TokenizerState {
 
     /// We found an ID
     Id,
+    /// We found the 'base' keyword and now parse its radix
+    Base,
+    /// We found a keyword that takes a path ('open', 'load_elf' or 'load_pe') and now parse its path
+    Path,
+
+    /// We found a '='
+    Equals,
+    /// We found a '!'
+    Exclamation,
+    /// We found a '+'
+    Plus,
+    /// We found a '-'
+    Minus,
+    /// We found a '*'
+    Star,
+    /// We found a '/'
+    Slash,
+    /// We found a '<'
+    Less,
+    /// We found a '>'
+    Greater,
+    /// We found a '.'
+    Dot,
+    /// We found a '..'
+    DotDot,
 
     /// We found a '0'
     Zero,
@@ -92,6 +132,18 @@ enum TokenizerState {
     FirstDigit,
     /// The state for when we find a digit
     Digit,
+    /// The state for when we find a size suffix after a decimal number
+    Suffix,
+    /// The state for when we find a digit after the '#' of a radix literal
+    RadixDigit,
+    /// The state for when we found a '.' after a decimal number, which is either a decimal point or the start of a range
+    DecimalPoint,
+    /// The state for when we find a digit after the decimal point of a float
+    Fraction,
+    /// The state for when we NEED a digit (or sign) of the exponent of a float
+    FirstExponentDigit,
+    /// The state for when we find a digit of the exponent of a float
+    ExponentDigit,
     /// The state for when we NEED a hexadecimal digit
     FirstHexDigit,
     /// The state for when we find a hexadecimal digit
@@ -100,6 +152,17 @@ enum TokenizerState {
     FirstBinaryDigit,
     /// The state for when we find a binary digit
     BinaryDigit,
+    /// The state for when we NEED an octal digit
+    FirstOctalDigit,
+    /// The state for when we find an octal digit
+    OctalDigit,
+
+    /// The state for when we're inside a character literal
+    Chars,
+    /// The state for when we found a backslash in a character literal
+    CharsEscape,
+    /// The state for when we're parsing the two digits of a '\x' escape in a character literal
+    CharsHexEscape,
 
     /// The state for when we encountered an unknown token and want to consume it
     UnknownToken,
@@ -171,27 +234,37 @@ impl<'a> Tokenizer<'a> {
         self.temp.push((c, pos));
     }
 
+    /// Returns the next character on the stream without consuming it.
+    /// 
+    /// **Returns**  
+    /// The next character, or '\0' if the end of the stream has been reached.
+    fn peek(&mut self) -> &'a str {
+        let (c, pos) = self.getc();
+        self.putc(c, pos);
+        return c;
+    }
+
     /// Parses the given char that is a digit as an integer and adds it to the given buffer.
     /// 
     /// **Arguments**
     ///  * `value`: The value to add the character to.
     ///  * `c`: The character that is a digit.
-    ///  * `radix`: The radix value for the conversion. Can either be '2' for binary, '10' for decimal or '16' for hex.
+    ///  * `radix`: The radix value for the conversion. Can either be '2' for binary, '8' for octal, '10' for decimal or '16' for hex.
     /// 
     /// **Returns**  
     /// Whether or not parsing was successful. If it was, returns None, or the error message otherwise.
-    fn parse_const(value: &mut u64, c: &str, radix: u32) -> Option<String> {
+    fn parse_const(value: &mut u128, c: &str, radix: u32) -> Option<String> {
         // Convert the digit to a value
-        let v: u64 = c.chars().next().unwrap().to_digit(radix).unwrap() as u64;
+        let v: u128 = c.chars().next().unwrap().to_digit(radix).unwrap() as u128;
 
         // Add it to the value while trying to prevent overflow
-        let mut result = value.checked_mul(radix as u64);
+        let mut result = value.checked_mul(radix as u128);
         if result == None {
-            return Some(String::from("overflow for u64"));
+            return Some(String::from("overflow for u128"));
         }
         result = result.unwrap().checked_add(v);
         if result == None {
-            return Some(String::from("overflow for u64"));
+            return Some(String::from("overflow for u128"));
         }
         *value = result.unwrap();
 
@@ -201,6 +274,65 @@ impl<'a> Tokenizer<'a> {
 
 
 
+    /// Parses the given digits of a radix literal.
+    /// 
+    /// **Arguments**
+    ///  * `digits`: The digits to parse (case-insensitive, without separators).
+    ///  * `radix`: The radix of the digits, which can be anything from 2 up to and including 36.
+    /// 
+    /// **Returns**  
+    /// The parsed value, or an error message if a digit does not exist in the radix or the value overflows.
+    fn parse_radix(digits: &str, radix: u32) -> Result<u128, String> {
+        let mut value: u128 = 0;
+        for c in digits.chars() {
+            if c.to_digit(radix).is_none() { return Err(format!("Digit '{}' does not exist in base {}", c, radix)); }
+            if let Some(err) = Tokenizer::parse_const(&mut value, &c.to_string(), radix) { return Err(format!("Encountered an {}", err)); }
+        }
+        return Ok(value);
+    }
+
+    /// Parses the given float constant into a token.
+    /// 
+    /// **Arguments**
+    ///  * `parsed`: The float constant as it was written (optionally prefixed by '0d' and with separators).
+    ///  * `pos1`: The start position of the constant in the input string.
+    ///  * `pos2`: The end position (inclusive) of the constant in the input string.
+    /// 
+    /// **Returns**  
+    /// A FLOAT token with the parsed value, or an Error token if the constant is not a (finite) float.
+    fn parse_float(parsed: &str, pos1: usize, pos2: usize) -> Token {
+        let digits: String = parsed.trim_start_matches("0d").trim_start_matches("0D").chars().filter(|c| *c != '_' && *c != '\'').collect();
+        match digits.parse::<f64>() {
+            Ok(value) if value.is_finite() => { return Token::new(TerminalKind::FLOAT(value), pos1, pos2); }
+            Ok(_)                          => { return Token::new(TerminalKind::Error(format!("Float constant '{}' is too large.", parsed)), pos1, pos2); }
+            Err(_)                         => { return Token::new(TerminalKind::Error(format!("Float constant '{}' is not a valid float.", parsed)), pos1, pos2); }
+        }
+    }
+
+    /// Parses the given size suffix of a decimal constant.
+    /// 
+    /// **Arguments**
+    ///  * `suffix`: The suffix to parse (case-insensitive).
+    /// 
+    /// **Returns**  
+    /// The value the constant should be multiplied with, or None if the suffix is unknown.
+    fn parse_suffix(suffix: &str) -> Option<u128> {
+        match suffix.to_lowercase().as_str() {
+            "k" | "ki" | "kib" => { Some(1 << 10) }
+            "m" | "mi" | "mib" => { Some(1 << 20) }
+            "g" | "gi" | "gib" => { Some(1 << 30) }
+            "t" | "ti" | "tib" => { Some(1 << 40) }
+            "kb"               => { Some(1_000) }
+            "mb"               => { Some(1_000_000) }
+            "gb"               => { Some(1_000_000_000) }
+            "tb"               => { Some(1_000_000_000_000) }
+            "page" | "pages"   => { Some(0x1000) }
+            _                  => { None }
+        }
+    }
+
+
+
     /// Tries to get the next token from the input stream.
     /// 
     /// **Returns**  
@@ -209,7 +341,15 @@ impl<'a> Tokenizer<'a> {
         // Loop to emulate the destinaton
         let mut start_pos: usize = usize::MAX;
         let mut parsed_buffer: String = String::new();
-        let mut value_buffer: u64 = 0;
+        let mut value_buffer: u128 = 0;
+        let mut value_error: Option<String> = None;
+        let mut suffix_buffer: String = String::new();
+        let mut digits_buffer: String = String::new();
+        let mut radix_buffer: u128 = 0;
+        let mut path_buffer: String = String::new();
+        let mut quote: &str = "'";
+        let mut chars_buffer: Vec<u8> = Vec::new();
+        let mut escape_buffer: String = String::new();
         let mut state = TokenizerState::Start;
         loop {
             // Match the state
@@ -233,33 +373,102 @@ impl<'a> Tokenizer<'a> {
                         continue;
                     } else if is_numeric!(c) {
                         // A number; might be decimal
-                        Tokenizer::parse_const(&mut value_buffer, c, 10);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 10) { value_error = Some(err); }
                         parsed_buffer.push_str(c);
                         state = TokenizerState::Digit;
                         continue;
 
                     } else if c == "=" {
-                        // An equals sign!
-                        return Token::new(TerminalKind::EQUALS, start_pos, pos);
+                        // An equals sign, or possibly a comparison
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Equals;
+                        continue;
+                    } else if c == "!" {
+                        // Possibly a not-equal sign
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Exclamation;
+                        continue;
 
                     } else if c == "+" {
-                        // A plus sign!
-                        return Token::new(TerminalKind::PLUS, start_pos, pos);
+                        // A plus sign, or possibly a compound assignment
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Plus;
+                        continue;
                     } else if c == "-" {
-                        // A plus sign!
-                        return Token::new(TerminalKind::MINUS, start_pos, pos);
+                        // A minus sign, or possibly a compound assignment
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Minus;
+                        continue;
                     } else if c == "*" {
-                        // A plus sign!
-                        return Token::new(TerminalKind::MULTIPLY, start_pos, pos);
+                        // Possibly a power sign
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Star;
+                        continue;
                     } else if c == "/" {
-                        // A plus sign!
-                        return Token::new(TerminalKind::DIVIDE, start_pos, pos);
+                        // A divide sign, or possibly a compound assignment
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Slash;
+                        continue;
+                    } else if c == "%" {
+                        // A modulo sign!
+                        return Token::new(TerminalKind::MODULO, start_pos, pos);
+                    } else if c == "<" {
+                        // A less-than sign, or possibly a left shift
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Less;
+                        continue;
+                    } else if c == ">" {
+                        // A greater-than sign, or possibly a right shift
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Greater;
+                        continue;
+                    } else if c == "&" {
+                        // An and sign!
+                        return Token::new(TerminalKind::AND, start_pos, pos);
+                    } else if c == "|" {
+                        // An or sign!
+                        return Token::new(TerminalKind::OR, start_pos, pos);
+                    } else if c == "^" {
+                        // A xor sign!
+                        return Token::new(TerminalKind::XOR, start_pos, pos);
+                    } else if c == "~" {
+                        // A not sign!
+                        return Token::new(TerminalKind::NOT, start_pos, pos);
+                    } else if c == "'" || c == "\"" {
+                        // The start of a character literal
+                        parsed_buffer.push_str(c);
+                        quote = c;
+                        state = TokenizerState::Chars;
+                        continue;
                     } else if c == "(" {
                         // A plus sign!
                         return Token::new(TerminalKind::LBRACKET, start_pos, pos);
                     } else if c == ")" {
                         // A plus sign!
                         return Token::new(TerminalKind::RBRACKET, start_pos, pos);
+                    } else if c == "[" {
+                        // A left square bracket!
+                        return Token::new(TerminalKind::LSQUARE, start_pos, pos);
+                    } else if c == "]" {
+                        // A right square bracket!
+                        return Token::new(TerminalKind::RSQUARE, start_pos, pos);
+                    } else if c == "," {
+                        // A comma!
+                        return Token::new(TerminalKind::COMMA, start_pos, pos);
+                    } else if c == ";" {
+                        // A semicolon!
+                        return Token::new(TerminalKind::SEMICOLON, start_pos, pos);
+                    } else if c == "?" {
+                        // A question mark!
+                        return Token::new(TerminalKind::QUESTION, start_pos, pos);
+                    } else if c == ":" {
+                        // A colon!
+                        return Token::new(TerminalKind::COLON, start_pos, pos);
+                    } else if c == "." {
+                        // Possibly the start of a range
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Dot;
+                        continue;
 
                     } else if is_whitespace!(c) {
                         // A whitespace; simply consume it, then try again
@@ -285,22 +494,69 @@ impl<'a> Tokenizer<'a> {
                         // While it's an ID, keep parsing
                         parsed_buffer.push_str(c);
                         continue;
-                    } else if is_separator!(c) {
+                    } else if c.eq(".") {
+                        // A dot followed by another ID character continues a dotted name (e.g., 'text.offset'); otherwise, it starts a range
+                        let (c2, pos2) = self.getc();
+                        self.putc(c2, pos2);
+                        if is_id_start!(c2) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
+                    }
+                    if is_separator!(c) {
                         self.putc(c, pos);
 
                         // If we've parsed a keyword, return that instead
                         if parsed_buffer.eq("dec") {
                             return Token::new(TerminalKind::TODEC, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("sdec") {
+                            return Token::new(TerminalKind::TOSDEC, start_pos, pos - 1);
                         } else if parsed_buffer.eq("hex") {
                             return Token::new(TerminalKind::TOHEX, start_pos, pos - 1);
                         } else if parsed_buffer.eq("bin") {
                             return Token::new(TerminalKind::TOBIN, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("oct") {
+                            return Token::new(TerminalKind::TOOCT, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("float") {
+                            return Token::new(TerminalKind::TOFLOAT, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("base") {
+                            // It's only a conversion if a radix follows it, so look past the whitespace (and put it back) to see if 'base' isn't just a variable
+                            let mut skipped = Vec::new();
+                            while is_whitespace!(self.peek()) { skipped.push(self.getc()); }
+                            let radix_follows = is_numeric!(self.peek());
+                            for (c, pos) in skipped.into_iter().rev() { self.putc(c, pos); }
+
+                            // If so, also parse the radix that follows it
+                            if radix_follows {
+                                state = TokenizerState::Base;
+                                continue;
+                            }
+                        } else if parsed_buffer.eq("u8") {
+                            return Token::new(TerminalKind::TOU8, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("u16") {
+                            return Token::new(TerminalKind::TOU16, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("u32") {
+                            return Token::new(TerminalKind::TOU32, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("u64") {
+                            return Token::new(TerminalKind::TOU64, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("u128") {
+                            return Token::new(TerminalKind::TOU128, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("in") {
+                            return Token::new(TerminalKind::IN, start_pos, pos - 1);
                         } else if parsed_buffer.eq("del") {
                             return Token::new(TerminalKind::DEL, start_pos, pos - 1);
                         } else if parsed_buffer.eq("delall") {
                             return Token::new(TerminalKind::DELALL, start_pos, pos - 1);
                         } else if parsed_buffer.eq("show_vars") {
                             return Token::new(TerminalKind::SHOWVARS, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("open") || parsed_buffer.eq("load_elf") || parsed_buffer.eq("load_pe") {
+                            // Also parse the path that follows it
+                            state = TokenizerState::Path;
+                            continue;
+                        } else if parsed_buffer.eq("dump") {
+                            return Token::new(TerminalKind::DUMP, start_pos, pos - 1);
+                        } else if parsed_buffer.eq("set") {
+                            return Token::new(TerminalKind::SET, start_pos, pos - 1);
                         } else if parsed_buffer.eq("clear_hist") {
                             return Token::new(TerminalKind::CLEARHIST, start_pos, pos - 1);
                         } else if parsed_buffer.eq("help") {
@@ -321,6 +577,208 @@ impl<'a> Tokenizer<'a> {
 
 
 
+                TokenizerState::Base => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // Skip whitespace until the radix, and then parse it
+                    if is_whitespace!(c) && digits_buffer.is_empty() {
+                        continue;
+                    } else if is_numeric!(c) {
+                        digits_buffer.push_str(c);
+                        continue;
+                    } else if is_separator!(c) && !digits_buffer.is_empty() {
+                        // Stop parsing and return, if it's a valid radix
+                        self.putc(c, pos);
+                        match digits_buffer.parse::<u32>() {
+                            Ok(radix) if (2..=36).contains(&radix) => { return Token::new(TerminalKind::TOBASE(radix), start_pos, pos - 1); }
+                            _ => { return Token::new(TerminalKind::Error(format!("Radix {} is not in the range 2 to 36.", digits_buffer)), start_pos, pos - 1); }
+                        }
+                    }
+
+                    // We expected a radix!
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::Error(format!("Expected a radix after 'base'.")), start_pos, pos - 1);
+                }
+
+                TokenizerState::Path => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // Skip whitespace until the path, and then take everything up to the end of the statement
+                    if is_whitespace!(c) && path_buffer.is_empty() {
+                        continue;
+                    } else if !c.eq(";") && !c.eq("\0") {
+                        path_buffer.push_str(c);
+                        continue;
+                    }
+                    self.putc(c, pos);
+
+                    // The path may be quoted to keep its surrounding whitespace
+                    let mut path = path_buffer.trim_end();
+                    if path.len() >= 2 && ((path.starts_with('"') && path.ends_with('"')) || (path.starts_with('\'') && path.ends_with('\''))) {
+                        path = &path[1..path.len() - 1];
+                    }
+                    if path.is_empty() { return Token::new(TerminalKind::Error(format!("Expected a path after '{}'.", parsed_buffer)), start_pos, pos - 1); }
+                    if parsed_buffer.eq("load_elf") { return Token::new(TerminalKind::LOADELF(String::from(path)), start_pos, pos - 1); }
+                    if parsed_buffer.eq("load_pe") { return Token::new(TerminalKind::LOADPE(String::from(path)), start_pos, pos - 1); }
+                    return Token::new(TerminalKind::OPEN(String::from(path)), start_pos, pos - 1);
+                }
+
+
+
+                TokenizerState::Equals => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // A second '=' makes it a comparison
+                    if c.eq("=") {
+                        return Token::new(TerminalKind::ISEQUAL, start_pos, pos);
+                    }
+
+                    // Otherwise, it's an assignment; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::EQUALS, start_pos, pos - 1);
+                }
+
+                TokenizerState::Exclamation => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // Only an '=' makes it a comparison
+                    if c.eq("=") {
+                        return Token::new(TerminalKind::NOTEQUAL, start_pos, pos);
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::Plus => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // An '=' makes it a compound assignment
+                    if c.eq("=") {
+                        return Token::new(TerminalKind::PLUSEQUALS, start_pos, pos);
+                    }
+
+                    // Otherwise, it's an addition; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::PLUS, start_pos, pos - 1);
+                }
+
+                TokenizerState::Minus => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // An '=' makes it a compound assignment
+                    if c.eq("=") {
+                        return Token::new(TerminalKind::MINUSEQUALS, start_pos, pos);
+                    }
+
+                    // Otherwise, it's a subtraction; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::MINUS, start_pos, pos - 1);
+                }
+
+                TokenizerState::Star => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // A second '*' makes it a power, an '=' makes it a compound assignment
+                    if c.eq("*") {
+                        return Token::new(TerminalKind::POWER, start_pos, pos);
+                    } else if c.eq("=") {
+                        return Token::new(TerminalKind::MULTIPLYEQUALS, start_pos, pos);
+                    }
+
+                    // Otherwise, it's a multiplication; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::MULTIPLY, start_pos, pos - 1);
+                }
+
+                TokenizerState::Slash => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // An '=' makes it a compound assignment
+                    if c.eq("=") {
+                        return Token::new(TerminalKind::DIVIDEEQUALS, start_pos, pos);
+                    }
+
+                    // Otherwise, it's a division; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::DIVIDE, start_pos, pos - 1);
+                }
+
+                TokenizerState::Dot => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // Only a second '.' makes it a range
+                    if c.eq(".") {
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::DotDot;
+                        continue;
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::DotDot => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // A '+' means the range is given by its length
+                    if c.eq("+") {
+                        return Token::new(TerminalKind::DOTDOTPLUS, start_pos, pos);
+                    }
+
+                    // Otherwise, it's a range with an end; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::DOTDOT, start_pos, pos - 1);
+                }
+
+                TokenizerState::Less => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // A second '<' makes it a shift, an '=' makes it a less-than-or-equal
+                    if c.eq("<") {
+                        return Token::new(TerminalKind::LSHIFT, start_pos, pos);
+                    } else if c.eq("=") {
+                        return Token::new(TerminalKind::LESSEQUAL, start_pos, pos);
+                    }
+
+                    // Otherwise, it's a less-than; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::LESS, start_pos, pos - 1);
+                }
+
+                TokenizerState::Greater => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // A second '>' makes it a shift, an '=' makes it a greater-than-or-equal
+                    if c.eq(">") {
+                        return Token::new(TerminalKind::RSHIFT, start_pos, pos);
+                    } else if c.eq("=") {
+                        return Token::new(TerminalKind::GREATEREQUAL, start_pos, pos);
+                    }
+
+                    // Otherwise, it's a greater-than; put the character back
+                    self.putc(c, pos);
+                    return Token::new(TerminalKind::GREATER, start_pos, pos - 1);
+                }
+
+
+
                 TokenizerState::Zero => {
                     // Get the next character
                     let (c, pos) = self.getc();
@@ -341,12 +799,28 @@ impl<'a> Tokenizer<'a> {
                         parsed_buffer.push_str(c);
                         state = TokenizerState::FirstBinaryDigit;
                         continue;
+                    } else if c.eq("o") || c.eq("O") {
+                        // It's octal
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::FirstOctalDigit;
+                        continue;
                     } else if is_numeric!(c) {
                         // Also decimal
-                        Tokenizer::parse_const(&mut value_buffer, c, 10);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 10) { value_error = Some(err); }
                         parsed_buffer.push_str(c);
                         state = TokenizerState::Digit;
                         continue;
+                    } else if is_digit_separator!(c) {
+                        // Still decimal, but skip the separator (which must be followed by a digit)
+                        if is_numeric!(self.peek()) {
+                            parsed_buffer.push_str(c);
+                            state = TokenizerState::Digit;
+                            continue;
+                        }
+                    } else if c.eq(".") {
+                        // It may be the decimal point of a float
+                        state = TokenizerState::DecimalPoint;
+                        continue;
                     } else if is_separator!(c) {
                         // It's a zero
                         self.putc(c, pos);
@@ -366,7 +840,7 @@ impl<'a> Tokenizer<'a> {
                     // As long as its a digit, keep parsing
                     if is_numeric!(c) {
                         parsed_buffer.push_str(c);
-                        Tokenizer::parse_const(&mut value_buffer, c, 10);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 10) { value_error = Some(err); }
                         state = TokenizerState::Digit;
                         continue;
                     } else if is_separator!(c) {
@@ -388,12 +862,185 @@ impl<'a> Tokenizer<'a> {
                     // As long as its a digit, keep parsing
                     if is_numeric!(c) {
                         parsed_buffer.push_str(c);
-                        Tokenizer::parse_const(&mut value_buffer, c, 10);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 10) { value_error = Some(err); }
+                        continue;
+                    } else if is_digit_separator!(c) {
+                        // Skip separators, but only between two digits
+                        if is_numeric!(self.peek()) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
+                    } else if c.eq(".") {
+                        // It may be the decimal point of a float
+                        state = TokenizerState::DecimalPoint;
                         continue;
                     } else if is_separator!(c) {
                         // Stop parsing and return
                         self.putc(c, pos);
+                        if let Some(err) = value_error { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an {}.", parsed_buffer, err)), start_pos, pos - 1); }
                         return Token::new(TerminalKind::DEC(value_buffer), start_pos, pos - 1);
+                    } else if is_id_start!(c) {
+                        // It's the start of a size suffix
+                        parsed_buffer.push_str(c);
+                        suffix_buffer.push_str(c);
+                        state = TokenizerState::Suffix;
+                        continue;
+                    } else if c.eq("#") {
+                        // It's the radix of a radix literal instead
+                        parsed_buffer.push_str(c);
+                        radix_buffer = value_buffer;
+                        state = TokenizerState::RadixDigit;
+                        continue;
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::Suffix => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // As long as it's part of the suffix, keep parsing
+                    if is_id!(c) {
+                        parsed_buffer.push_str(c);
+                        suffix_buffer.push_str(c);
+                        continue;
+                    } else if is_separator!(c) {
+                        // Stop parsing and scale the value by the suffix
+                        self.putc(c, pos);
+                        if let Some(err) = value_error { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an {}.", parsed_buffer, err)), start_pos, pos - 1); }
+                        match Tokenizer::parse_suffix(&suffix_buffer) {
+                            Some(multiplier) => {
+                                match value_buffer.checked_mul(multiplier) {
+                                    Some(value) => { return Token::new(TerminalKind::DEC(value), start_pos, pos - 1); }
+                                    None        => { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an overflow for u128.", parsed_buffer)), start_pos, pos - 1); }
+                                }
+                            }
+                            None => {
+                                return Token::new(TerminalKind::Error(format!("Unknown size suffix '{}' in constant '{}'.", suffix_buffer, parsed_buffer)), start_pos, pos - 1);
+                            }
+                        }
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::DecimalPoint => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // Only a digit makes it a float; otherwise, the dot is the start of a range and the number is just a decimal
+                    if is_numeric!(c) {
+                        parsed_buffer.push_str(".");
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::Fraction;
+                        continue;
+                    }
+                    self.putc(c, pos);
+                    self.putc(".", pos - 1);
+                    if let Some(err) = value_error { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an {}.", parsed_buffer, err)), start_pos, pos - 2); }
+                    return Token::new(TerminalKind::DEC(value_buffer), start_pos, pos - 2);
+                }
+
+                TokenizerState::Fraction => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // As long as its a digit, keep parsing
+                    if is_numeric!(c) {
+                        parsed_buffer.push_str(c);
+                        continue;
+                    } else if is_digit_separator!(c) {
+                        // Skip separators, but only between two digits
+                        if is_numeric!(self.peek()) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
+                    } else if c.eq("e") || c.eq("E") {
+                        // It's the start of the exponent
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::FirstExponentDigit;
+                        continue;
+                    } else if is_separator!(c) {
+                        // Stop parsing and return
+                        self.putc(c, pos);
+                        return Tokenizer::parse_float(&parsed_buffer, start_pos, pos - 1);
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::FirstExponentDigit => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // The exponent may start with a sign
+                    if is_numeric!(c) || c.eq("+") || c.eq("-") {
+                        parsed_buffer.push_str(c);
+                        state = TokenizerState::ExponentDigit;
+                        continue;
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::ExponentDigit => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // As long as its a digit, keep parsing
+                    if is_numeric!(c) {
+                        parsed_buffer.push_str(c);
+                        continue;
+                    } else if is_separator!(c) {
+                        // Stop parsing and return
+                        self.putc(c, pos);
+                        return Tokenizer::parse_float(&parsed_buffer, start_pos, pos - 1);
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::RadixDigit => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // As long as it's a digit (in any radix), keep parsing
+                    if is_digit_separator!(c) {
+                        // Skip separators, but only between two digits
+                        let next = self.peek();
+                        if !digits_buffer.is_empty() && is_id!(next) && !is_digit_separator!(next) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
+                    } else if is_id!(c) {
+                        parsed_buffer.push_str(c);
+                        digits_buffer.push_str(c);
+                        continue;
+                    } else if is_separator!(c) {
+                        // Stop parsing and return, if the radix and the digits are valid
+                        self.putc(c, pos);
+                        if value_error.is_some() || radix_buffer < 2 || radix_buffer > 36 { return Token::new(TerminalKind::Error(format!("Radix {} in constant '{}' is not in the range 2 to 36.", &parsed_buffer[..parsed_buffer.find('#').unwrap()], parsed_buffer)), start_pos, pos - 1); }
+                        if digits_buffer.is_empty() { return Token::new(TerminalKind::Error(format!("Expected at least one digit after '{}'.", parsed_buffer)), start_pos, pos - 1); }
+                        match Tokenizer::parse_radix(&digits_buffer, radix_buffer as u32) {
+                            Ok(value)   => { return Token::new(TerminalKind::RADIX(radix_buffer as u32, value), start_pos, pos - 1); }
+                            Err(reason) => { return Token::new(TerminalKind::Error(format!("{} in constant '{}'.", reason, parsed_buffer)), start_pos, pos - 1); }
+                        }
                     }
 
                     // Unknown token; consume it
@@ -409,7 +1056,7 @@ impl<'a> Tokenizer<'a> {
                     // As long as its a digit, keep parsing
                     if is_hex!(c) {
                         parsed_buffer.push_str(c);
-                        Tokenizer::parse_const(&mut value_buffer, c, 16);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 16) { value_error = Some(err); }
                         state = TokenizerState::HexDigit;
                         continue;
                     } else if is_separator!(c) {
@@ -431,11 +1078,18 @@ impl<'a> Tokenizer<'a> {
                     // As long as its a hexdigit, keep parsing
                     if is_hex!(c) {
                         parsed_buffer.push_str(c);
-                        Tokenizer::parse_const(&mut value_buffer, c, 16);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 16) { value_error = Some(err); }
                         continue;
+                    } else if is_digit_separator!(c) {
+                        // Skip separators, but only between two digits
+                        if is_hex!(self.peek()) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
                     } else if is_separator!(c) {
                         // Stop parsing and return
                         self.putc(c, pos);
+                        if let Some(err) = value_error { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an {}.", parsed_buffer, err)), start_pos, pos - 1); }
                         return Token::new(TerminalKind::HEX(value_buffer), start_pos, pos - 1);
                     }
 
@@ -452,7 +1106,7 @@ impl<'a> Tokenizer<'a> {
                     // As long as its a digit, keep parsing
                     if is_binary!(c) {
                         parsed_buffer.push_str(c);
-                        Tokenizer::parse_const(&mut value_buffer, c, 2);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 2) { value_error = Some(err); }
                         state = TokenizerState::BinaryDigit;
                         continue;
                     } else if is_separator!(c) {
@@ -473,13 +1127,72 @@ impl<'a> Tokenizer<'a> {
 
                     // As long as its a binarydigit, keep parsing
                     if is_binary!(c) {
-                        Tokenizer::parse_const(&mut value_buffer, c, 2);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 2) { value_error = Some(err); }
+                        parsed_buffer.push_str(c);
+                        continue;
+                    } else if is_digit_separator!(c) {
+                        // Skip separators, but only between two digits
+                        if is_binary!(self.peek()) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
+                    } else if is_separator!(c) {
+                        // Stop parsing and return
+                        self.putc(c, pos);
+                        if let Some(err) = value_error { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an {}.", parsed_buffer, err)), start_pos, pos - 1); }
+                        return Token::new(TerminalKind::BIN(value_buffer), start_pos, pos - 1);
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+
+
+                TokenizerState::FirstOctalDigit => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // As long as its a digit, keep parsing
+                    if is_octal!(c) {
+                        parsed_buffer.push_str(c);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 8) { value_error = Some(err); }
+                        state = TokenizerState::OctalDigit;
+                        continue;
+                    } else if is_separator!(c) {
+                        // We expected a digit!
+                        self.putc(c, pos);
+                        return Token::new(TerminalKind::Error(format!("Expected at least one digit after '0o'.")), start_pos, pos - 1);
+                    }
+
+                    // Unknown token; consume it
+                    self.putc(c, pos);
+                    state = TokenizerState::UnknownToken;
+                    continue;
+                }
+
+                TokenizerState::OctalDigit => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // As long as its an octaldigit, keep parsing
+                    if is_octal!(c) {
                         parsed_buffer.push_str(c);
+                        if let Some(err) = Tokenizer::parse_const(&mut value_buffer, c, 8) { value_error = Some(err); }
                         continue;
+                    } else if is_digit_separator!(c) {
+                        // Skip separators, but only between two digits
+                        if is_octal!(self.peek()) {
+                            parsed_buffer.push_str(c);
+                            continue;
+                        }
                     } else if is_separator!(c) {
                         // Stop parsing and return
                         self.putc(c, pos);
-                        return Token::new(TerminalKind::BIN(value_buffer), start_pos, pos + 1);
+                        if let Some(err) = value_error { return Token::new(TerminalKind::Error(format!("Constant '{}' causes an {}.", parsed_buffer, err)), start_pos, pos - 1); }
+                        return Token::new(TerminalKind::OCT(value_buffer), start_pos, pos - 1);
                     }
 
                     // Unknown token; consume it
@@ -490,6 +1203,90 @@ impl<'a> Tokenizer<'a> {
 
 
 
+                TokenizerState::Chars => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+
+                    // Stop if the input ended before the literal did
+                    if pos > self.max_pos {
+                        self.putc(c, pos);
+                        return Token::new(TerminalKind::Error(format!("Unterminated character literal {}.", parsed_buffer)), start_pos, pos - 1);
+                    }
+                    parsed_buffer.push_str(c);
+
+                    // Decide what to do with the character
+                    if c == quote {
+                        // The end of the literal; check if it makes sense
+                        if let Some(err) = value_error { return Token::new(TerminalKind::Error(err), start_pos, pos); }
+                        if chars_buffer.is_empty() { return Token::new(TerminalKind::Error(format!("Empty character literal {}.", parsed_buffer)), start_pos, pos); }
+                        if chars_buffer.len() > 16 { return Token::new(TerminalKind::Error(format!("Character literal {} is longer than 16 bytes.", parsed_buffer)), start_pos, pos); }
+                        return Token::new(TerminalKind::CHARS(chars_buffer), start_pos, pos);
+                    } else if c == "\\" {
+                        // An escape sequence
+                        state = TokenizerState::CharsEscape;
+                        continue;
+                    }
+
+                    // Otherwise, add the (UTF-8) bytes of the character to the literal
+                    chars_buffer.extend_from_slice(c.as_bytes());
+                    continue;
+                }
+
+                TokenizerState::CharsEscape => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+                    if pos > self.max_pos {
+                        self.putc(c, pos);
+                        state = TokenizerState::Chars;
+                        continue;
+                    }
+                    parsed_buffer.push_str(c);
+
+                    // Match the escaped character
+                    state = TokenizerState::Chars;
+                    match c {
+                        "n"  => { chars_buffer.push(b'\n'); }
+                        "r"  => { chars_buffer.push(b'\r'); }
+                        "t"  => { chars_buffer.push(b'\t'); }
+                        "0"  => { chars_buffer.push(0); }
+                        "\\" => { chars_buffer.push(b'\\'); }
+                        "'"  => { chars_buffer.push(b'\''); }
+                        "\"" => { chars_buffer.push(b'"'); }
+                        "x"  => {
+                            escape_buffer.clear();
+                            state = TokenizerState::CharsHexEscape;
+                        }
+                        _ => {
+                            // Unknown escape; remember the error, but consume the rest of the literal first
+                            if value_error.is_none() { value_error = Some(format!("Unknown escape sequence '\\{}' in character literal.", c)); }
+                        }
+                    }
+                    continue;
+                }
+
+                TokenizerState::CharsHexEscape => {
+                    // Get the next character
+                    let (c, pos) = self.getc();
+                    if pos > self.max_pos || !is_hex!(c) {
+                        // Not a valid escape; remember the error, but let the literal state deal with the character
+                        self.putc(c, pos);
+                        if value_error.is_none() { value_error = Some(format!("Expected two hexadecimal digits after '\\x' in character literal.")); }
+                        state = TokenizerState::Chars;
+                        continue;
+                    }
+                    parsed_buffer.push_str(c);
+
+                    // Add the digit, and stop once we have two
+                    escape_buffer.push_str(c);
+                    if escape_buffer.len() == 2 {
+                        chars_buffer.push(u8::from_str_radix(&escape_buffer, 16).unwrap());
+                        state = TokenizerState::Chars;
+                    }
+                    continue;
+                }
+
+
+
                 TokenizerState::UnknownToken => {
                     // Get the next char
                     let (c, pos) = self.getc();
@@ -0,0 +1,88 @@
+/* BINARY.rs
+ *   by Lut99
+ *
+ * Created:
+ *   17 Oct 2026, 15:21:08
+ * Last edited:
+ *   17 Oct 2026, 18:10:52
+ * Auto updated?
+ *   Yes
+ *
+ * Description:
+ *   Contains the binary file that can be opened with the 'open' command, so
+ *   that expressions can read values from it.
+**/
+
+use crate::elf::{Elf, ElfError};
+use crate::pe::{Pe, PeError};
+
+
+/***** LIBRARY STRUCTS *****/
+/// Represents a binary file that has been opened to read values from.
+#[derive(Debug)]
+pub struct BinaryFile {
+    /// The path of the file, as given by the user.
+    path  : String,
+    /// The raw contents of the file.
+    bytes : Vec<u8>,
+    /// The headers of the file if it's an ELF file, or why it isn't one.
+    elf   : Result<Elf, ElfError>,
+    /// The headers of the file if it's a PE file, or why it isn't one.
+    pe    : Result<Pe, PeError>,
+}
+
+impl BinaryFile {
+    /// Constructor for the BinaryFile, which reads the entire file into memory and parses its headers if it's an ELF or PE file.
+    ///
+    /// **Arguments**
+    ///  * `path`: The path of the file to open.
+    ///
+    /// **Returns**
+    /// The newly opened BinaryFile, or the std::io::Error if the file could not be read.
+    pub fn open(path: &str) -> Result<BinaryFile, std::io::Error> {
+        let bytes = std::fs::read(path)?;
+        let mut file = BinaryFile {
+            path  : String::from(path),
+            bytes : bytes,
+            elf   : Err(ElfError::NotElf),
+            pe    : Err(PeError::NotPe),
+        };
+        file.elf = Elf::parse(&file);
+        file.pe  = Pe::parse(&file);
+        return Ok(file);
+    }
+
+
+
+    /// Returns the given number of bytes at the given offset in the file.
+    ///
+    /// **Arguments**
+    ///  * `offset`: The offset of the first byte to return.
+    ///  * `n_bytes`: The number of bytes to return.
+    ///
+    /// **Returns**
+    /// The bytes as a slice, or None if (part of) them lie outside of the file.
+    pub fn read(&self, offset: u128, n_bytes: usize) -> Option<&[u8]> {
+        let start = usize::try_from(offset).ok()?;
+        let end = start.checked_add(n_bytes)?;
+        return self.bytes.get(start..end);
+    }
+
+
+
+    /// Returns the path of the file, as given by the user.
+    #[inline]
+    pub fn path(&self) -> &str { &self.path }
+
+    /// Returns the size of the file in bytes.
+    #[inline]
+    pub fn len(&self) -> usize { self.bytes.len() }
+
+    /// Returns the headers of the file if it's an ELF file, or the reason why it isn't one otherwise.
+    #[inline]
+    pub fn elf(&self) -> Result<&Elf, &ElfError> { self.elf.as_ref() }
+
+    /// Returns the headers of the file if it's a PE file, or the reason why it isn't one otherwise.
+    #[inline]
+    pub fn pe(&self) -> Result<&Pe, &PeError> { self.pe.as_ref() }
+}
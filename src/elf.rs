@@ -0,0 +1,338 @@
+/* ELF.rs
+ *   by Lut99
+ *
+ * Created:
+ *   17 Oct 2026, 17:02:41
+ * Last edited:
+ *   17 Oct 2026, 17:02:41
+ * Auto updated?
+ *   Yes
+ *
+ * Description:
+ *   Contains a small parser for ELF32 and ELF64 files, which extracts the
+ *   section headers and program headers so that the 'load_elf' command can
+ *   define variables for them.
+**/
+
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use crate::binary::BinaryFile;
+
+
+/***** CONSTANTS *****/
+/// The magic bytes that start every ELF file
+const ELF_MAGIC: [u8; 4] = [0x7F, b'E', b'L', b'F'];
+/// The type of program headers that describe a loadable segment
+pub const PT_LOAD: u32 = 1;
+
+
+
+
+
+/***** ERRORS *****/
+/// Lists the errors that can occur while parsing an ELF file.
+#[derive(Debug)]
+pub enum ElfError {
+    /// The file does not start with the ELF magic bytes.
+    NotElf,
+    /// The file has an unknown class (i.e., it's not 32-bit or 64-bit).
+    UnknownClass{ class: u8 },
+    /// The file has an unknown data encoding (i.e., it's not little- or big-endian).
+    UnknownEncoding{ encoding: u8 },
+    /// Some part of the file lies outside of it.
+    Truncated{ what: &'static str },
+}
+
+impl Display for ElfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            ElfError::NotElf                         => write!(f, "Not an ELF file (missing magic bytes)"),
+            ElfError::UnknownClass{ class }          => write!(f, "Unknown ELF class {} (expected 1 for 32-bit or 2 for 64-bit)", class),
+            ElfError::UnknownEncoding{ encoding }    => write!(f, "Unknown ELF data encoding {} (expected 1 for little-endian or 2 for big-endian)", encoding),
+            ElfError::Truncated{ what }              => write!(f, "The {} lie outside of the file", what),
+        }
+    }
+}
+
+impl std::error::Error for ElfError {}
+
+
+
+
+
+/***** HELPER STRUCTS *****/
+/// Reads header fields from the file, taking the class and endianness of the ELF file into account.
+struct Reader<'a> {
+    /// The file to read from.
+    file          : &'a BinaryFile,
+    /// Whether the file is a 64-bit ELF file (and thus has 64-bit addresses and offsets).
+    is_64         : bool,
+    /// Whether the file is little-endian.
+    little_endian : bool,
+}
+
+impl<'a> Reader<'a> {
+    /// Reads an unsigned integer of the given number of bytes at the given offset.
+    ///
+    /// **Arguments**
+    ///  * `offset`: The offset of the integer in the file.
+    ///  * `n_bytes`: The size of the integer in bytes.
+    ///  * `what`: A description of what we're reading, used in the error if it lies outside of the file.
+    ///
+    /// **Returns**
+    /// The integer, or an ElfError::Truncated if it lies outside of the file.
+    fn uint(&self, offset: u128, n_bytes: usize, what: &'static str) -> Result<u64, ElfError> {
+        let bytes = match self.file.read(offset, n_bytes) {
+            Some(bytes) => bytes,
+            None        => { return Err(ElfError::Truncated{ what }); }
+        };
+        let mut value: u64 = 0;
+        for i in 0..n_bytes {
+            let byte = if self.little_endian { bytes[n_bytes - 1 - i] } else { bytes[i] };
+            value = (value << 8) | byte as u64;
+        }
+        return Ok(value);
+    }
+
+    /// Reads an address or offset at the given offset, which is 4 bytes for 32-bit files and 8 bytes for 64-bit files.
+    ///
+    /// **Arguments**
+    ///  * `offset`: The offset of the word in the file.
+    ///  * `what`: A description of what we're reading, used in the error if it lies outside of the file.
+    ///
+    /// **Returns**
+    /// The word, or an ElfError::Truncated if it lies outside of the file.
+    #[inline]
+    fn word(&self, offset: u128, what: &'static str) -> Result<u64, ElfError> {
+        return self.uint(offset, if self.is_64 { 8 } else { 4 }, what);
+    }
+}
+
+
+
+
+
+/***** LIBRARY STRUCTS *****/
+/// Represents a single section header of an ELF file.
+#[derive(Clone, Debug)]
+pub struct ElfSection {
+    /// The name of the section, as found in the section header string table (e.g., '.text').
+    pub name   : String,
+    /// The virtual address of the section once it's loaded in memory.
+    pub addr   : u64,
+    /// The offset of the section in the file.
+    pub offset : u64,
+    /// The size of the section in bytes.
+    pub size   : u64,
+}
+
+/// Represents a single program header (segment) of an ELF file.
+#[derive(Clone, Debug)]
+pub struct ElfSegment {
+    /// The type of the segment (e.g., PT_LOAD).
+    pub kind   : u32,
+    /// The offset of the segment in the file.
+    pub offset : u64,
+    /// The virtual address of the segment once it's loaded in memory.
+    pub vaddr  : u64,
+    /// The number of bytes of the segment in the file.
+    pub filesz : u64,
+    /// The number of bytes of the segment in memory, which may be more than in the file (e.g., for the BSS).
+    pub memsz  : u64,
+}
+
+
+
+/// Represents the headers of an ELF file that we're interested in.
+#[derive(Clone, Debug)]
+pub struct Elf {
+    /// Whether the file is a 64-bit ELF file.
+    pub is_64    : bool,
+    /// The section headers of the file, in order.
+    pub sections : Vec<ElfSection>,
+    /// The program headers of the file, in order.
+    pub segments : Vec<ElfSegment>,
+}
+
+impl Elf {
+    /// Parses the ELF header, the section headers and the program headers of the given file.
+    ///
+    /// **Arguments**
+    ///  * `file`: The opened file to parse.
+    ///
+    /// **Returns**
+    /// The parsed Elf, or an ElfError if the file is not a (valid) ELF file.
+    pub fn parse(file: &BinaryFile) -> Result<Elf, ElfError> {
+        // Check the identification bytes
+        let ident = match file.read(0, 16) {
+            Some(ident) => ident,
+            None        => { return Err(ElfError::NotElf); }
+        };
+        if ident[0..4] != ELF_MAGIC { return Err(ElfError::NotElf); }
+        let is_64 = match ident[4] {
+            1     => false,
+            2     => true,
+            class => { return Err(ElfError::UnknownClass{ class }); }
+        };
+        let little_endian = match ident[5] {
+            1        => true,
+            2        => false,
+            encoding => { return Err(ElfError::UnknownEncoding{ encoding }); }
+        };
+        let reader = Reader{ file, is_64, little_endian };
+
+        // Read the location of the headers from the ELF header
+        let what = "ELF header fields";
+        let (phoff, shoff, rest) = if is_64 {
+            (reader.word(0x20, what)?, reader.word(0x28, what)?, 0x36)
+        } else {
+            (reader.word(0x1C, what)?, reader.word(0x20, what)?, 0x2A)
+        };
+        let phentsize = reader.uint(rest, 2, what)?;
+        let phnum     = reader.uint(rest + 2, 2, what)?;
+        let shentsize = reader.uint(rest + 4, 2, what)?;
+        let shnum     = reader.uint(rest + 6, 2, what)?;
+        let shstrndx  = reader.uint(rest + 8, 2, what)?;
+
+        // Read the program headers
+        let what = "program headers";
+        let mut segments: Vec<ElfSegment> = Vec::with_capacity(phnum as usize);
+        for i in 0..phnum {
+            let base = phoff as u128 + (i * phentsize) as u128;
+            if is_64 {
+                segments.push(ElfSegment {
+                    kind   : reader.uint(base, 4, what)? as u32,
+                    offset : reader.word(base + 0x08, what)?,
+                    vaddr  : reader.word(base + 0x10, what)?,
+                    filesz : reader.word(base + 0x20, what)?,
+                    memsz  : reader.word(base + 0x28, what)?,
+                });
+            } else {
+                segments.push(ElfSegment {
+                    kind   : reader.uint(base, 4, what)? as u32,
+                    offset : reader.word(base + 0x04, what)?,
+                    vaddr  : reader.word(base + 0x08, what)?,
+                    filesz : reader.word(base + 0x10, what)?,
+                    memsz  : reader.word(base + 0x14, what)?,
+                });
+            }
+        }
+
+        // Read the section headers, remembering the offset of their names for now
+        let what = "section headers";
+        let mut sections: Vec<(u64, ElfSection)> = Vec::with_capacity(shnum as usize);
+        for i in 0..shnum {
+            let base = shoff as u128 + (i * shentsize) as u128;
+            let name = reader.uint(base, 4, what)?;
+            if is_64 {
+                sections.push((name, ElfSection {
+                    name   : String::new(),
+                    addr   : reader.word(base + 0x10, what)?,
+                    offset : reader.word(base + 0x18, what)?,
+                    size   : reader.word(base + 0x20, what)?,
+                }));
+            } else {
+                sections.push((name, ElfSection {
+                    name   : String::new(),
+                    addr   : reader.word(base + 0x0C, what)?,
+                    offset : reader.word(base + 0x10, what)?,
+                    size   : reader.word(base + 0x14, what)?,
+                }));
+            }
+        }
+
+        // Resolve the names using the section header string table (if there is one)
+        if let Some((_, strtab)) = sections.get(shstrndx as usize).cloned() {
+            for (name_offset, section) in sections.iter_mut() {
+                let mut name: Vec<u8> = Vec::new();
+                let mut offset = strtab.offset as u128 + *name_offset as u128;
+                while let Some(byte) = file.read(offset, 1) {
+                    if byte[0] == 0 { break; }
+                    name.push(byte[0]);
+                    offset += 1;
+                }
+                section.name = String::from_utf8_lossy(&name).into_owned();
+            }
+        }
+
+        // Done
+        return Ok(Elf {
+            is_64,
+            sections : sections.into_iter().map(|(_, section)| section).collect(),
+            segments,
+        });
+    }
+
+
+
+    /// Finds the loadable segment that contains the given virtual address in memory (which includes the part that's not in the file, like the BSS).
+    ///
+    /// **Arguments**
+    ///  * `vaddr`: The virtual address to look for.
+    ///
+    /// **Returns**
+    /// The index and the segment of the first PT_LOAD segment containing the address, or None if there is no such segment.
+    pub fn segment_at_vaddr(&self, vaddr: u64) -> Option<(usize, &ElfSegment)> {
+        return self.segments.iter().enumerate().find(|(_, segment)| segment.kind == PT_LOAD && vaddr >= segment.vaddr && vaddr - segment.vaddr < segment.memsz);
+    }
+
+    /// Finds the loadable segment that contains the given offset in the file.
+    ///
+    /// **Arguments**
+    ///  * `offset`: The file offset to look for.
+    ///
+    /// **Returns**
+    /// The index and the segment of the first PT_LOAD segment containing the offset, or None if there is no such segment.
+    pub fn segment_at_offset(&self, offset: u64) -> Option<(usize, &ElfSegment)> {
+        return self.segments.iter().enumerate().find(|(_, segment)| segment.kind == PT_LOAD && offset >= segment.offset && offset - segment.offset < segment.filesz);
+    }
+
+
+
+    /// Returns the variables that describe the sections and segments of this file.
+    ///
+    /// Every named section 'X' defines 'X.offset', 'X.addr' and 'X.size', where the name is stripped of its leading dot and any character that may not appear in an identifier is replaced by an underscore (e.g., '.note.gnu.build-id' becomes 'note.gnu.build_id'). Every program header 'N' defines 'phdrN.offset', 'phdrN.addr', 'phdrN.size' and 'phdrN.memsz'.
+    ///
+    /// **Returns**
+    /// A list of (name, value) pairs for every variable.
+    pub fn variables(&self) -> Vec<(String, u64)> {
+        let mut result: Vec<(String, u64)> = Vec::with_capacity(3 * self.sections.len() + 4 * self.segments.len());
+        for section in self.sections.iter() {
+            let name = identifier(&section.name);
+            if name.is_empty() { continue; }
+            result.push((format!("{}.offset", name), section.offset));
+            result.push((format!("{}.addr", name), section.addr));
+            result.push((format!("{}.size", name), section.size));
+        }
+        for (i, segment) in self.segments.iter().enumerate() {
+            result.push((format!("phdr{}.offset", i), segment.offset));
+            result.push((format!("phdr{}.addr", i), segment.vaddr));
+            result.push((format!("phdr{}.size", i), segment.filesz));
+            result.push((format!("phdr{}.memsz", i), segment.memsz));
+        }
+        return result;
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Converts the given section name to a (dotted) identifier, which is also used for the sections of PE files.
+///
+/// **Arguments**
+///  * `name`: The name of the section (e.g., '.rela.dyn').
+///
+/// **Returns**
+/// The name as an identifier (e.g., 'rela.dyn'), or an empty string if nothing remains of it.
+pub fn identifier(name: &str) -> String {
+    // Replace everything that's not allowed in an identifier
+    let name: String = name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '.' { c } else { '_' }).collect();
+
+    // Make sure every dotted part starts with an ID-start character
+    let parts: Vec<String> = name.split('.').filter(|part| !part.is_empty()).map(|part| {
+        if part.starts_with(|c: char| c.is_ascii_digit()) { format!("_{}", part) } else { String::from(part) }
+    }).collect();
+    return parts.join(".");
+}
@@ -15,13 +15,24 @@
 mod ast;
 mod traversals;
 mod session;
+mod settings;
+mod binary;
+mod elf;
+mod pe;
 
 use rustyline::error::ReadlineError;
 use rustyline::Editor;
 
 use ast::parser::ValueKind;
+use ast::parser::ValueWidth;
 use ast::parser::ASTNode;
+use ast::symbols::Symbol;
 use ast::symbol_table::SymbolTable;
+use ast::symbol_table::Variable;
+use ast::builtins::BUILTINS;
+use settings::Settings;
+use settings::ByteDisplay;
+use binary::BinaryFile;
 #[allow(unused_imports)]
 use traversals::print_tree;
 use traversals::trim;
@@ -33,6 +44,258 @@ use traversals::symbol_table;
 /***** CONSTANTS *****/
 /// The default file to always load if it's a session
 const DEFAULT_SESSION_PATH: &str = "./offsetcalculator.session";
+/// The number of bytes that 'dump' shows if no length is given
+const DEFAULT_DUMP_LENGTH: u128 = 64;
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Groups the given digits by inserting an underscore between every group of the given size, counted from the right.
+/// 
+/// **Arguments**
+///  * `digits`: The digits to group.
+///  * `size`: The number of digits in each group.
+/// 
+/// **Returns**  
+/// The grouped digits as a String.
+fn group_digits(digits: &str, size: usize) -> String {
+    let mut result = String::with_capacity(digits.len() + digits.len() / size);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % size == 0 { result.push('_'); }
+        result.push(c);
+    }
+    return result;
+}
+
+/// Writes the given value as digits in the given radix.
+/// 
+/// **Arguments**
+///  * `value`: The value to write.
+///  * `radix`: The radix to write the value in (from 2 up to and including 36).
+/// 
+/// **Returns**  
+/// The digits as a String, where digits above 9 are written as uppercase letters.
+fn radix_digits(mut value: u128, radix: u32) -> String {
+    let mut digits: Vec<char> = Vec::new();
+    loop {
+        digits.push(std::char::from_digit((value % radix as u128) as u32, radix).unwrap().to_ascii_uppercase());
+        value /= radix as u128;
+        if value == 0 { break; }
+    }
+    return digits.iter().rev().collect();
+}
+
+/// Formats the given value according to the given kind.
+/// 
+/// **Arguments**
+///  * `value`: The value to format.
+///  * `kind`: The ValueKind that determines the representation.
+///  * `signed`: Whether the value is a signed value. Only affects decimals; the other representations show the raw two's complement bits.
+///  * `width`: The ValueWidth of the value, which determines the number of two's complement bits shown.
+///  * `slice_bits`: If the value is a bit slice, the number of bits in it. Hexadecimal and binary values are then always padded to that many bits.
+///  * `settings`: The Settings that determine whether values are padded and/or grouped, and how booleans are shown.
+/// 
+/// **Returns**  
+/// The formatted value as a String.
+fn format_value(value: u128, kind: ValueKind, signed: bool, width: ValueWidth, slice_bits: Option<u32>, settings: &Settings) -> String {
+    // Get the raw bits and the number of digits to pad to
+    let bits = value & width.mask();
+    let digits = match slice_bits {
+        Some(slice_bits) => { slice_bits as usize }
+        None             => { if settings.pad { width.bits() as usize } else { 0 } }
+    };
+    let radix_prefix = if let ValueKind::Radix(radix) = kind { format!("{}#", radix) } else { String::new() };
+    let (prefix, digits, group_size) = match kind {
+        ValueKind::Decimal => {
+            if signed && (value as i128) < 0 { ("-", (value as i128).unsigned_abs().to_string(), 3) }
+            else { ("", value.to_string(), 3) }
+        }
+        ValueKind::Hexadecimal => { ("0x", format!("{:0w$X}", bits, w = (digits + 3) / 4), 4) }
+        ValueKind::Binary => { ("0b", format!("{:0w$b}", bits, w = digits), 4) }
+        ValueKind::Octal => { ("0o", format!("{:o}", bits), 3) }
+        ValueKind::Radix(radix) => { (radix_prefix.as_str(), radix_digits(bits, radix), 3) }
+        ValueKind::Float => {
+            // Show the bits as the float they encode, which is never grouped (the types traversal made sure it's 32- or 64-bit)
+            if width.or(settings.width) == ValueWidth::U32 { return format!("{:?}", f32::from_bits(bits as u32)); }
+            return format!("{:?}", f64::from_bits(bits as u64));
+        }
+        ValueKind::Boolean => {
+            if settings.bool_text { return String::from(if value != 0 { "true" } else { "false" }); }
+            ("", value.to_string(), 3)
+        }
+        _ => {
+            panic!("Unknown ValueKind {:?} in AST's root node; this should never happen!", kind);
+        }
+    };
+
+    // Group the digits if told to do so
+    if settings.group { return format!("{}{}", prefix, group_digits(&digits, group_size)); }
+    return format!("{}{}", prefix, digits);
+}
+
+/// Formats the bytes of the given value in the given byte order.
+/// 
+/// **Arguments**
+///  * `value`: The value of which to format the bytes.
+///  * `width`: The ValueWidth of the value, which determines the number of bytes shown.
+///  * `little_endian`: Whether to show the bytes in little-endian order instead of big-endian order.
+/// 
+/// **Returns**  
+/// The bytes as space-separated hexadecimal pairs (e.g., '78 56 34 12').
+fn format_bytes(value: u128, width: ValueWidth, little_endian: bool) -> String {
+    let n_bytes = (width.bits() / 8) as usize;
+    let mut bytes: Vec<String> = (0..n_bytes).map(|i| format!("{:02X}", (value >> (8 * i)) as u8)).collect();
+    if !little_endian { bytes.reverse(); }
+    return bytes.join(" ");
+}
+
+/// Formats the given result according to the given kind, which may either be a single value or a range.
+/// 
+/// Single values are followed by their bytes if the Settings say so.
+/// 
+/// **Arguments**
+///  * `value`: The value to format, or the start of the range.
+///  * `end`: The (exclusive) end of the range if the result is one, or None otherwise.
+///  * `kind`: The ValueKind that determines the representation.
+///  * `signed`: Whether the value is a signed value.
+///  * `width`: The ValueWidth of the value.
+///  * `slice_bits`: If the value is a bit slice, the number of bits in it.
+///  * `settings`: The Settings that determine whether values are padded and/or grouped, and how booleans are shown.
+/// 
+/// **Returns**  
+/// The formatted result as a String.
+fn format_result(value: u128, end: Option<u128>, kind: ValueKind, signed: bool, width: ValueWidth, slice_bits: Option<u32>, settings: &Settings) -> String {
+    // Ranges are simply shown as their two bounds
+    if let Some(end) = end {
+        return format!("{}..{}", format_value(value, kind, signed, width, None, settings), format_value(end, kind, signed, width, None, settings));
+    }
+
+    // Show the value's bytes as well if told to do so (booleans don't really have any)
+    let result = format_value(value, kind, signed, width, slice_bits, settings);
+    if kind == ValueKind::Boolean { return result; }
+    let width = width.or(settings.width);
+    match settings.bytes {
+        ByteDisplay::Off    => { return result; }
+        ByteDisplay::Little => { return format!("{}  [LE: {}]", result, format_bytes(value, width, true)); }
+        ByteDisplay::Big    => { return format!("{}  [BE: {}]", result, format_bytes(value, width, false)); }
+        ByteDisplay::Both   => { return format!("{}  [LE: {}]  [BE: {}]", result, format_bytes(value, width, true), format_bytes(value, width, false)); }
+    }
+}
+
+/// Prints a hexdump of the rows of the given file around the given offset, where the byte at the offset is highlighted.
+/// 
+/// **Arguments**
+///  * `file`: The BinaryFile to dump.
+///  * `offset`: The offset of the first byte to show, which is highlighted.
+///  * `n_bytes`: The number of bytes to show from the offset on (which is rounded up to whole rows and clipped to the end of the file).
+fn print_dump(file: &BinaryFile, offset: u128, n_bytes: u128) {
+    // Make sure there is something to show
+    if offset >= file.len() as u128 {
+        eprintln!("   Offset {} lies outside of the {}-byte file.", offset, file.len());
+        return;
+    }
+    let end = offset.saturating_add(n_bytes.max(1)).min(file.len() as u128);
+
+    // Print whole rows of 16 bytes
+    let mut row = offset - offset % 16;
+    while row < end {
+        let bytes = file.read(row, (file.len() as u128 - row).min(16) as usize).unwrap();
+
+        // The separators around the bytes are spaces (with an extra one halfway), except around the highlighted byte
+        let mut seps: Vec<String> = (0..17).map(|i| String::from(if i == 8 { "  " } else { " " })).collect();
+        if offset >= row && offset < row + 16 {
+            let i = (offset - row) as usize;
+            seps[i] = format!("{}[", &seps[i][1..]);
+            seps[i + 1] = format!("]{}", &seps[i + 1][1..]);
+        }
+
+        // Write the bytes both as hexadecimal and as ASCII
+        let mut hex = String::new();
+        let mut ascii = String::new();
+        for i in 0..16 {
+            hex.push_str(&seps[i]);
+            match bytes.get(i) {
+                Some(b) => {
+                    hex.push_str(&format!("{:02X}", b));
+                    ascii.push(if b.is_ascii_graphic() || *b == b' ' { *b as char } else { '.' });
+                }
+                None => { hex.push_str("  "); }
+            }
+        }
+        println!("   {:08X}:{}{} |{}|", row, hex, seps[16], ascii);
+
+        // Go to the next row
+        row += 16;
+    }
+    println!();
+}
+
+/// Runs a single statement through all traversals to compute its result.
+/// 
+/// **Arguments**
+///  * `ast`: The parsed AST of the statement.
+///  * `symtable`: The SymbolTable with the variables.
+///  * `settings`: The Settings that determine how the statement is computed.
+///  * `file`: The opened file that functions read from, if any.
+/// 
+/// **Returns**  
+/// The result (as a Variable) and its slice bits (if any) as a tuple (in that order), or None if an error occurred (which will already have been printed).
+fn evaluate(mut ast: ASTNode, symtable: &mut SymbolTable, settings: &Settings, file: Option<&BinaryFile>) -> Option<(Variable, Option<u32>)> {
+    // Trim it
+    ast = trim::traverse(ast);
+    // println!("Trimmed:");
+    // ast = print_tree::traverse(ast, symtable);
+    // Resolve the symbol table
+    let mut res = symbol_table::traverse(ast, symtable);
+    if let Some(ast) = res {
+        // Resolve the typing
+        res = types::traverse(ast, symtable, settings);
+        if let Some(ast) = res {
+            // Compute the result!
+            let mut value: u128 = 0;
+            let mut end: Option<u128> = None;
+            // println!("Typed:");
+            // let ast = print_tree::traverse(ast, symtable);
+            res = compute::traverse(ast, &mut value, &mut end, symtable, settings, file);
+            if let Some(ast) = res {
+                // Get the format of the result
+                let kind = ast::parser::get_kind(&ast);
+                let signed = ast::parser::get_signed(&ast);
+                let width = ast::parser::get_width(&ast);
+                let slice_bits = ast::parser::get_slice_bits(&ast);
+
+                // Done
+                return Some((Variable::new(kind, value, signed, width, end), slice_bits));
+            }
+        }
+    }
+
+    // Something went wrong
+    return None;
+}
+
+/// Runs a single statement through all traversals to compute its result, and stores that result in 'ans'.
+/// 
+/// **Arguments**
+///  * `ast`: The parsed AST of the statement.
+///  * `symtable`: The SymbolTable with the variables.
+///  * `settings`: The Settings that determine how the statement is computed.
+///  * `file`: The opened file that functions read from, if any.
+/// 
+/// **Returns**  
+/// The result (as a Variable) and its slice bits (if any) as a tuple (in that order), or None if an error occurred (which will already have been printed).
+fn run_statement(ast: ASTNode, symtable: &mut SymbolTable, settings: &Settings, file: Option<&BinaryFile>) -> Option<(Variable, Option<u32>)> {
+    // Compute the result
+    let (result, slice_bits) = evaluate(ast, symtable, settings, file)?;
+
+    // Store the ans in the symbol table
+    symtable.insert(String::from("ans"), result);
+
+    // Done
+    return Some((result, slice_bits));
+}
 
 
 
@@ -75,19 +338,18 @@ fn main() {
     // If the user gave a flag that immediately returns, handle it
     if args_dict.has_opt("exec") {
         // Get the value
-        let mut res: Option<ASTNode>;
         let value: &str = &args_dict.get_opt("exec").unwrap()[0];
 
         // Parse the value
-        let oast = ast::parser::parse(&value);
-        let mut ast: ast::parser::ASTNode;
-        match oast {
-            Some(node) => { ast = node; }
+        let oasts = ast::parser::parse(&value);
+        let asts: Vec<ASTNode>;
+        match oasts {
+            Some(nodes) => { asts = nodes; }
             None => { std::process::exit(-1); }
         }
 
         // Check if it's a command
-        let cmd_res = ast::parser::get_command(&ast);
+        let cmd_res = asts.first().and_then(|ast| ast::parser::get_command(ast));
         if let Some(_) = cmd_res {
             eprintln!("You cannot execute commands from --execute, only expressions.");
             std::process::exit(-1);
@@ -95,31 +357,21 @@ fn main() {
 
         // Prepare the symbol table
         let mut symtable = SymbolTable::new();
-        symtable.insert(String::from("ans"), (ValueKind::Undefined, 0));
-
-        // Trim it
-        ast = trim::traverse(ast);
-        // Resolve the symbol table
-        res = symbol_table::traverse(ast, &mut symtable);
-        if let Some(ast) = res {
-            // Resolve the typing
-            res = types::traverse(ast, &mut symtable);
-            if let Some(ast) = res {
-                // Compute the result!
-                let mut value: u64 = 0;
-                res = compute::traverse(ast, &mut value, &mut symtable);
-                if let Some(ast) = res {
-                    // Print the result in the correct format
-                    let kind = ast::parser::get_kind(&ast);
-                    match kind {
-                        ValueKind::Decimal => { println!("{}", value); }
-                        ValueKind::Hexadecimal => { println!("0x{:X}", value); }
-                        ValueKind::Binary => { println!("{:#b}", value); }
-                        _ => {
-                            panic!("Unknown ValueKind {:?} in AST's root node; this should never happen!", kind);
-                        }
+        symtable.insert(String::from("ans"), Variable::undefined());
+        // Prepare the settings
+        let settings = Settings::new();
+
+        // Run the statements one by one, stopping at the first error
+        let n_statements = asts.len();
+        for (i, ast) in asts.into_iter().enumerate() {
+            match run_statement(ast, &mut symtable, &settings, None) {
+                Some((Variable{ kind, value, signed, width, end }, slice_bits)) => {
+                    // Print the result in the correct format, if it's the last one or if we print them all
+                    if settings.print_all || i == n_statements - 1 {
+                        println!("{}", format_result(value, end, kind, signed, width, slice_bits, &settings));
                     }
                 }
+                None => { break; }
             }
         }
 
@@ -140,7 +392,13 @@ fn main() {
     /* REPL LOOP */
     // Prepare the symbol table
     let mut symtable = SymbolTable::new();
-    symtable.insert(String::from("ans"), (ValueKind::Undefined, 0));
+    symtable.insert(String::from("ans"), Variable::undefined());
+
+    // Prepare the settings
+    let mut settings = Settings::new();
+
+    // Prepare the file to read from, which is only opened once asked for
+    let mut file: Option<BinaryFile> = None;
 
     // Prepare the linereader
     let mut rl = Editor::<()>::new();
@@ -154,13 +412,21 @@ fn main() {
         }
 
         // Try to load the session
-        if let Err(reason) = session::load(path, &mut symtable, &mut rl) {
+        let mut file_path: Option<String> = None;
+        if let Err(reason) = session::load(path, &mut symtable, &mut rl, &mut file_path) {
             eprintln!("{}: WARNING: {}: Not loading session file.", reason.path(), reason);
         }
+
+        // Reopen the file that was open in the session
+        if let Some(file_path) = file_path {
+            match BinaryFile::open(&file_path) {
+                Ok(new_file) => { file = Some(new_file); }
+                Err(reason)  => { eprintln!("{}: WARNING: Could not reopen file '{}': {}.", path, file_path, reason); }
+            }
+        }
     }
 
     // Enter the REPL loop
-    let mut res: Option<ASTNode>;
     loop {
         let readline = rl.readline(" > ");
         match readline {
@@ -169,17 +435,15 @@ fn main() {
                 rl.add_history_entry(line.clone());
 
                 // Throw it thru the parser
-                let oast = ast::parser::parse(&line);
-                let mut ast: ast::parser::ASTNode;
-                match oast {
-                    Some(node) => { ast = node; }
+                let oasts = ast::parser::parse(&line);
+                let asts: Vec<ASTNode>;
+                match oasts {
+                    Some(nodes) => { asts = nodes; }
                     None => { continue; }
                 }
-                // println!("Parsed:");
-                // ast = print_tree::traverse(ast);
 
-                // Check if it's a command
-                let cmd_res = ast::parser::get_command(&ast);
+                // Check if it's a command (which is always the only statement)
+                let cmd_res = asts.first().and_then(|ast| ast::parser::get_command(ast));
                 if let Some(cmd) = cmd_res {
                     // Try to run it!
                     match cmd {
@@ -198,19 +462,112 @@ fn main() {
                             // Clear the symbol table
                             symtable.clear();
                             // Reinstate ans
-                            symtable.insert(String::from("ans"), (ValueKind::Undefined, 0));
+                            symtable.insert(String::from("ans"), Variable::undefined());
                             println!("   Cleared all variables.\n");
                             continue;
                         }
                         ASTNode::ShowVars { pos1: _, pos2: _ } => {
                             // Print the symbol table
                             println!("   Currently defined variables:");
-                            for (identifier, (kind, value)) in symtable.iter() {
-                                println!(" - {}\t\t{:?}, {}", identifier, kind, value);
+                            for (identifier, Variable{ kind, value, signed, width, end }) in symtable.iter() {
+                                println!(" - {}\t\t{:?}, {}, {}, {}", identifier, kind, if *signed { "signed" } else { "unsigned" }, width, if *kind != ValueKind::Undefined { format_result(*value, *end, *kind, *signed, *width, None, &settings) } else { value.to_string() });
                             }
                             println!();
                             continue;
                         }
+                        ASTNode::Set { ref setting, ref value, pos1: _, pos2: _ } => {
+                            // Try to change the setting
+                            match settings.set(setting, value) {
+                                Ok(_)       => { println!("   Set '{}' to '{}'.\n", setting, value); }
+                                Err(reason) => { eprintln!("   {}.", reason); }
+                            }
+                            continue;
+                        }
+                        ASTNode::Open { ref path, pos1: _, pos2: _ } => {
+                            // Try to open the file, which replaces the previous one
+                            match BinaryFile::open(path) {
+                                Ok(new_file) => {
+                                    println!("   Opened '{}' ({} bytes).\n", path, new_file.len());
+                                    file = Some(new_file);
+                                }
+                                Err(reason) => { eprintln!("   Could not open file '{}': {}.", path, reason); }
+                            }
+                            continue;
+                        }
+                        ASTNode::LoadElf { ref path, pos1: _, pos2: _ } => {
+                            // Try to open the file, which also parses its headers
+                            let new_file = match BinaryFile::open(path) {
+                                Ok(new_file) => new_file,
+                                Err(reason)  => { eprintln!("   Could not open file '{}': {}.", path, reason); continue; }
+                            };
+                            let elf = match new_file.elf() {
+                                Ok(elf)     => elf,
+                                Err(reason) => { eprintln!("   Could not load ELF file '{}': {}.", path, reason); continue; }
+                            };
+
+                            // Define a variable for every section and segment
+                            let width = if elf.is_64 { ValueWidth::U64 } else { ValueWidth::U32 };
+                            for (name, value) in elf.variables() {
+                                symtable.insert(name, Variable::new(ValueKind::Hexadecimal, value as u128, false, width, None));
+                            }
+
+                            // The ELF file also replaces the opened file
+                            println!("   Loaded ELF file '{}' ({} sections, {} program headers).\n", path, elf.sections.len(), elf.segments.len());
+                            file = Some(new_file);
+                            continue;
+                        }
+                        ASTNode::LoadPe { ref path, pos1: _, pos2: _ } => {
+                            // Try to open the file, which also parses its headers
+                            let new_file = match BinaryFile::open(path) {
+                                Ok(new_file) => new_file,
+                                Err(reason)  => { eprintln!("   Could not open file '{}': {}.", path, reason); continue; }
+                            };
+                            let pe = match new_file.pe() {
+                                Ok(pe)      => pe,
+                                Err(reason) => { eprintln!("   Could not load PE file '{}': {}.", path, reason); continue; }
+                            };
+
+                            // Define the image base and a variable for every section (whose RVAs and offsets are always 32-bit)
+                            symtable.insert(String::from("ImageBase"), Variable::new(ValueKind::Hexadecimal, pe.image_base as u128, false, if pe.is_64 { ValueWidth::U64 } else { ValueWidth::U32 }, None));
+                            for (name, value) in pe.variables() {
+                                symtable.insert(name, Variable::new(ValueKind::Hexadecimal, value as u128, false, ValueWidth::U32, None));
+                            }
+
+                            // The PE file also replaces the opened file
+                            println!("   Loaded PE file '{}' ({} sections).\n", path, pe.sections.len());
+                            file = Some(new_file);
+                            continue;
+                        }
+                        ASTNode::Dump { ref expr, ref len, pos1: _, pos2: _ } => {
+                            // Compute the offset, which may also be a range that determines the length (without storing it in 'ans')
+                            let (offset, signed, mut n_bytes) = match evaluate(*expr.clone(), &mut symtable, &settings, file.as_ref()) {
+                                Some((Variable{ kind: _, value, signed, width: _, end }, _)) => { (value, signed, if let Some(end) = end { end.wrapping_sub(value) } else { DEFAULT_DUMP_LENGTH }) }
+                                None                                                           => { continue; }
+                            };
+                            if signed && (offset as i128) < 0 {
+                                eprintln!("   {}: Cannot dump at negative offset {}.", expr.pos().0, offset as i128);
+                                continue;
+                            }
+
+                            // Compute the length, if any
+                            if let Some(len) = len {
+                                match evaluate(*len.clone(), &mut symtable, &settings, file.as_ref()) {
+                                    Some((Variable{ kind: _, value, signed: _, width: _, end: None }, _)) => { n_bytes = value; }
+                                    Some(_) => {
+                                        eprintln!("   {}: Expected a value as length, got a range.", len.pos().0);
+                                        continue;
+                                    }
+                                    None => { continue; }
+                                }
+                            }
+
+                            // Dump the opened file
+                            match file {
+                                Some(ref file) => { print_dump(file, offset, n_bytes); }
+                                None           => { eprintln!("   No file opened (see 'open <path>')."); }
+                            }
+                            continue;
+                        }
                         ASTNode::ClearHist { pos1: _, pos2: _ } => {
                             // Clear the history
                             rl.clear_history();
@@ -223,32 +580,116 @@ fn main() {
                             println!();
                             println!("   Expressions:");
                             println!("     Expressions in the calculator are written as normal programming language math");
-                            println!("     expressions.");
+                            println!("     expressions. Multiple expressions can be given on one line by separating them");
+                            println!("     with a semicolon (e.g., 'a = 0x100; a + 0x20'); they are run in order.");
                             println!("     You can use the following constants:");
                             println!("      - A decimal constant (e.g., '42') or a constant prefixed by '0d' (e.g.,");
                             println!("        '0d42')");
                             println!("      - A hexadecimal constant prefixed by '0x' (e.g., '0x2A')");
                             println!("      - A binary constant prefixed by '0b' (e.g., '0b101010')");
+                            println!("      - An octal constant prefixed by '0o' (e.g., '0o52')");
+                            println!("      - A constant in any radix from 2 to 36, prefixed by the radix and '#'");
+                            println!("        (e.g., '36#ZZ')");
+                            println!("      - A float constant (e.g., '1.5' or '6.02e23'), but only as the argument of");
+                            println!("        f32bits() or f64bits()");
+                            println!("      - A character literal of up to 16 bytes between single or double quotes");
+                            println!("        (e.g., 'ELF' or \"PK\\x03\\x04\")");
+                            println!("     Digits in constants may be separated by '_' or ''' (e.g., '0xFFFF_FFFF').");
+                            println!("     Decimal constants may have a size suffix: 'K', 'M', 'G' or 'T' (optionally");
+                            println!("     followed by 'iB') for binary multiples, 'KB', 'MB', 'GB' or 'TB' for decimal");
+                            println!("     multiples and 'page(s)' for 4 KiB pages (e.g., '4KiB' or '2pages').");
                             println!("     Furthermore, you can also use the following operators (in order of");
                             println!("     precedence):");
                             println!("      - <id> = <expr>: Creates a variable with the given ID and sets its value to");
                             println!("        the given expression.");
+                            println!("      - <id> += <expr>, <id> -= <expr>, <id> *= <expr>, <id> /= <expr>: Adds,");
+                            println!("        subtracts, multiplies or divides an existing variable by the given");
+                            println!("        expression and stores the result in it.");
                             println!("      - dec <expr>: Converts the representation of the given expression to");
                             println!("        decimal.");
+                            println!("      - sdec <expr>: Converts the representation of the given expression to");
+                            println!("        signed decimal, and computes with it as a signed (two's complement) value.");
                             println!("      - hex <expr>: Converts the representation of the given expression to");
                             println!("        hexadecimal.");
                             println!("      - bin <expr>: Converts the representation of the given expression to");
                             println!("        binary.");
+                            println!("      - oct <expr>: Converts the representation of the given expression to");
+                            println!("        octal.");
+                            println!("      - base <N> <expr>: Converts the representation of the given expression to");
+                            println!("        the radix N, which lies between 2 and 36.");
+                            println!("      - float <expr>: Shows the bits of the given 32- or 64-bit expression as the");
+                            println!("        IEEE-754 float they encode.");
+                            println!("      - u8(<expr>), u16(<expr>), u32(<expr>), u64(<expr>), u128(<expr>): Computes");
+                            println!("        the given expression with the given width in bits.");
+                            println!("      - <expr>[<hi>:<lo>], <expr>[<bit>]: Extracts the bits from the upper to the");
+                            println!("        lower bit (or a single bit) of the given expression, shifted down.");
+                            println!("      - ~<expr>: Bitwise NOT on the given expression.");
+                            println!("      - -<expr>: Negates the given expression. Only works for signed values, unless");
+                            println!("        overflows wrap (in which case the two's complement is returned).");
+                            println!("      - <expr> ** <expr>: Raises the first expression to the power of the second.");
                             println!("      - <expr> * <expr>: Multiplication on the given two expressions.");
                             println!("      - <expr> / <expr>: Division on the given two expressions.");
+                            println!("      - <expr> % <expr>: Modulo (remainder of the division) on the given two");
+                            println!("        expressions.");
                             println!("      - <expr> + <expr>: Addition on the given two expressions.");
                             println!("      - <expr> - <expr>: Subtraction on the given two expressions.");
+                            println!("      - <expr> << <expr>: Left shift of the first expression by the second.");
+                            println!("      - <expr> >> <expr>: Right shift of the first expression by the second.");
+                            println!("      - <expr> & <expr>: Bitwise AND on the given two expressions.");
+                            println!("      - <expr> ^ <expr>: Bitwise XOR on the given two expressions.");
+                            println!("      - <expr> | <expr>: Bitwise OR on the given two expressions.");
+                            println!("      - <expr>..<expr>: The half-open range from the first expression up to (but");
+                            println!("        not including) the second.");
+                            println!("      - <expr>..+<expr>: The half-open range starting at the first expression with");
+                            println!("        the second expression as its length.");
+                            println!("      - <expr> == <expr>, <expr> != <expr>, <expr> < <expr>, <expr> <= <expr>,");
+                            println!("        <expr> > <expr>, <expr> >= <expr>: Compares the given two expressions,");
+                            println!("        resulting in a boolean (true or false).");
+                            println!("      - <expr> in <range>: Checks if the given expression lies in the given range,");
+                            println!("        resulting in a boolean (true or false).");
+                            println!("      - <expr> ? <expr> : <expr>: Results in the second expression if the first");
+                            println!("        one is non-zero (true), or in the third expression otherwise.");
+                            println!("     Finally, you can call the following built-in functions:");
+                            for builtin in BUILTINS {
+                                println!("      - {}({}): {}", builtin.name, builtin.args.iter().map(|arg| format!("<{}>", arg)).collect::<Vec<String>>().join(", "), builtin.description);
+                            }
                             println!();
                             println!("   Commands:");
                             println!("     There are a few special command keywords:");
                             println!("      - 'del <id>': Deletes the variable with the given identifier.");
                             println!("      - 'delall': Deletes all variables, even 'ans' (resetting it to undefined).");
                             println!("      - 'show_vars': Shows a list of currently loaded variables and their values.");
+                            println!("      - 'set <setting> <value>': Changes a setting of the calculator. Supported are:");
+                            println!("         - 'signed on|off': If on, all constants are treated as signed (two's");
+                            println!("           complement) values.");
+                            println!("         - 'width 8|16|32|64|128': Sets the width in bits of values for which no");
+                            println!("           width is given.");
+                            println!("         - 'overflow check|wrap': Whether to throw an error or wrap around when a");
+                            println!("           value overflows its width.");
+                            println!("         - 'pad on|off': If on, hexadecimal and binary values are padded with");
+                            println!("           zeroes up to their width.");
+                            println!("         - 'endian big|little': The byte order in which character literals are");
+                            println!("           interpreted.");
+                            println!("         - 'group on|off': If on, digits are grouped with underscores (e.g.,");
+                            println!("           '0xFFFF_FFFF').");
+                            println!("         - 'boolean text|number': Whether booleans are shown as 'true' and");
+                            println!("           'false' or as '1' and '0'.");
+                            println!("         - 'print last|all': Whether only the result of the last expression on a");
+                            println!("           line is printed, or the results of all of them.");
+                            println!("         - 'bytes off|le|be|both': Whether the bytes of results are also shown in");
+                            println!("           little-endian and/or big-endian order (e.g., '0x1234  [LE: 34 12]').");
+                            println!("      - 'open <path>': Opens the given file, so that the byte(), u16le(), u32be(),");
+                            println!("        etc. functions read from it. It is reopened when the session is loaded.");
+                            println!("      - 'load_elf <path>': Opens the given ELF file and defines the variables");
+                            println!("        <section>.offset, <section>.addr and <section>.size for every section");
+                            println!("        (e.g., 'text.offset'), and phdr<N>.offset, phdr<N>.addr, phdr<N>.size and");
+                            println!("        phdr<N>.memsz for every program header.");
+                            println!("      - 'load_pe <path>': Opens the given PE file and defines the variable");
+                            println!("        ImageBase, and <section>.rva, <section>.raw and <section>.size for every");
+                            println!("        section (e.g., 'text.rva').");
+                            println!("      - 'dump <expr> [len]': Shows a hexdump of the opened file from the given");
+                            println!("        offset (or range) on, where the byte at the offset is highlighted. Shows");
+                            println!("        {} bytes if no length is given.", DEFAULT_DUMP_LENGTH);
                             println!("      - 'clear_hist': Clear the history of the REPL up to that point.");
                             println!("      - 'help': Shows an in-calculator help menu for expressions and commands.");
                             println!("      - 'exit': Exits the REPL.");
@@ -267,50 +708,24 @@ fn main() {
                     }
                 }
 
-                // Trim it
-                ast = trim::traverse(ast);
-                // println!("Trimmed:");
-                // ast = print_tree::traverse(ast);
-                // Resolve the symbol table
-                res = symbol_table::traverse(ast, &mut symtable);
-                if let Some(ast) = res {
-                    // Resolve the typing
-                    // println!("Resolved:");
-                    // let ast2 = print_tree::traverse(ast);
-                    res = types::traverse(ast, &mut symtable);
-                    // res = types::traverse(ast2, &mut symtable);
-                    if let Some(ast) = res {
-                        // Compute the result!
-                        let mut value: u64 = 0;
-                        // println!("Typed:");
-                        // let ast2 = print_tree::traverse(ast);
-                        res = compute::traverse(ast, &mut value, &mut symtable);
-                        // res = compute::traverse(ast2, &mut value, &mut symtable);
-                        if let Some(ast) = res {
-                            // Print the result in the correct format
-                            // println!("Computed:");
-                            // let ast2 = print_tree::traverse(ast);
-                            let kind = ast::parser::get_kind(&ast);
-                            // let kind = ast::parser::get_kind(&ast2);
-                            match kind {
-                                ValueKind::Decimal => { println!(" = {}", value); }
-                                ValueKind::Hexadecimal => { println!(" = 0x{:X}", value); }
-                                ValueKind::Binary => { println!(" = {:#b}", value); }
-                                _ => {
-                                    panic!("Unknown ValueKind {:?} in AST's root node; this should never happen!", kind);
-                                }
+                // Run the statements one by one, stopping at the first error
+                let n_statements = asts.len();
+                let mut printed = false;
+                for (i, ast) in asts.into_iter().enumerate() {
+                    match run_statement(ast, &mut symtable, &settings, file.as_ref()) {
+                        Some((Variable{ kind, value, signed, width, end }, slice_bits)) => {
+                            // Print the result in the correct format, if it's the last one or if we print them all
+                            if settings.print_all || i == n_statements - 1 {
+                                println!(" = {}", format_result(value, end, kind, signed, width, slice_bits, &settings));
+                                printed = true;
                             }
-
-                            // Store the ans in the symbol table
-                            symtable.get_mut("ans").unwrap().0 = kind;
-                            symtable.get_mut("ans").unwrap().1 = value;
-
-                            // Print an extra newline to close off
-                            println!();
                         }
+                        None => { break; }
                     }
                 }
 
+                // Print an extra newline to close off
+                if printed { println!(); }
             },
             Err(ReadlineError::Interrupted) => {
                 // Ctrl+C was pressed
@@ -337,7 +752,7 @@ fn main() {
         }
 
         // Save the session!
-        if let Err(reason) = session::save(path, &symtable, &rl) {
+        if let Err(reason) = session::save(path, &symtable, &rl, file.as_ref().map(|file| file.path())) {
             eprintln!("{}: WARNING: {}: Not saving session file.", reason.path(), reason);
         }
     }
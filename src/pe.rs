@@ -0,0 +1,352 @@
+/* PE.rs
+ *   by Lut99
+ *
+ * Created:
+ *   17 Oct 2026, 18:10:52
+ * Last edited:
+ *   17 Oct 2026, 18:10:52
+ * Auto updated?
+ *   Yes
+ *
+ * Description:
+ *   Contains a small parser for PE/COFF files (i.e., Windows executables),
+ *   which extracts the image base and the section headers so that the
+ *   'load_pe' command can define variables for them and RVAs can be
+ *   translated to file offsets.
+**/
+
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use crate::binary::BinaryFile;
+use crate::elf::identifier;
+
+
+/***** CONSTANTS *****/
+/// The magic bytes that start the DOS header of every PE file
+const DOS_MAGIC: [u8; 2] = [b'M', b'Z'];
+/// The signature that starts the NT headers
+const PE_SIGNATURE: [u8; 4] = [b'P', b'E', 0, 0];
+/// The magic number of the optional header of 32-bit (PE32) files
+const PE32_MAGIC: u64 = 0x10B;
+/// The magic number of the optional header of 64-bit (PE32+) files
+const PE32_PLUS_MAGIC: u64 = 0x20B;
+/// The size of a single section header
+const SECTION_HEADER_SIZE: u128 = 40;
+
+
+
+
+
+/***** ERRORS *****/
+/// Lists the errors that can occur while parsing a PE file.
+#[derive(Debug)]
+pub enum PeError {
+    /// The file does not start with the DOS magic bytes.
+    NotPe,
+    /// The NT headers do not start with the PE signature.
+    MissingSignature{ offset: u64 },
+    /// The optional header has an unknown magic number (i.e., it's not PE32 or PE32+).
+    UnknownMagic{ magic: u64 },
+    /// Some part of the file lies outside of it.
+    Truncated{ what: &'static str },
+}
+
+impl Display for PeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            PeError::NotPe                       => write!(f, "Not a PE file (missing 'MZ' magic bytes)"),
+            PeError::MissingSignature{ offset }  => write!(f, "Missing 'PE' signature at offset 0x{:X}", offset),
+            PeError::UnknownMagic{ magic }       => write!(f, "Unknown optional header magic 0x{:X} (expected 0x10B for PE32 or 0x20B for PE32+)", magic),
+            PeError::Truncated{ what }           => write!(f, "The {} lie outside of the file", what),
+        }
+    }
+}
+
+impl std::error::Error for PeError {}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Reads a little-endian unsigned integer of the given number of bytes at the given offset.
+///
+/// **Arguments**
+///  * `file`: The file to read from.
+///  * `offset`: The offset of the integer in the file.
+///  * `n_bytes`: The size of the integer in bytes.
+///  * `what`: A description of what we're reading, used in the error if it lies outside of the file.
+///
+/// **Returns**
+/// The integer, or a PeError::Truncated if it lies outside of the file.
+fn uint(file: &BinaryFile, offset: u128, n_bytes: usize, what: &'static str) -> Result<u64, PeError> {
+    let bytes = match file.read(offset, n_bytes) {
+        Some(bytes) => bytes,
+        None        => { return Err(PeError::Truncated{ what }); }
+    };
+    let mut value: u64 = 0;
+    for b in bytes.iter().rev() { value = (value << 8) | *b as u64; }
+    return Ok(value);
+}
+
+
+
+
+
+/***** LIBRARY STRUCTS *****/
+/// Represents a single section header of a PE file.
+#[derive(Clone, Debug)]
+pub struct PeSection {
+    /// The name of the section (e.g., '.text').
+    pub name         : String,
+    /// The RVA (address relative to the image base) of the section once it's loaded in memory.
+    pub rva          : u64,
+    /// The size of the section in memory.
+    pub virtual_size : u64,
+    /// The offset of the section in the file.
+    pub raw          : u64,
+    /// The size of the section in the file, which may be more (padding) or less (uninitialized data) than in memory.
+    pub raw_size     : u64,
+}
+
+impl PeSection {
+    /// Returns the size of the section in memory, which falls back to its size in the file if the virtual size is not given (as some linkers do).
+    #[inline]
+    pub fn size(&self) -> u64 { if self.virtual_size != 0 { self.virtual_size } else { self.raw_size } }
+}
+
+
+
+/// Represents the headers of a PE file that we're interested in.
+#[derive(Clone, Debug)]
+pub struct Pe {
+    /// Whether the file is a 64-bit (PE32+) file.
+    pub is_64           : bool,
+    /// The preferred address at which the image is loaded.
+    pub image_base      : u64,
+    /// The size of all headers, which are loaded as-is at the start of the image.
+    pub size_of_headers : u64,
+    /// The section headers of the file, in order.
+    pub sections        : Vec<PeSection>,
+}
+
+impl Pe {
+    /// Parses the DOS header, the NT headers and the section headers of the given file.
+    ///
+    /// **Arguments**
+    ///  * `file`: The opened file to parse.
+    ///
+    /// **Returns**
+    /// The parsed Pe, or a PeError if the file is not a (valid) PE file.
+    pub fn parse(file: &BinaryFile) -> Result<Pe, PeError> {
+        // Check the DOS header and find the NT headers with it
+        match file.read(0, 2) {
+            Some(magic) => { if magic != DOS_MAGIC { return Err(PeError::NotPe); } }
+            None        => { return Err(PeError::NotPe); }
+        }
+        let nt_offset = uint(file, 0x3C, 4, "DOS header fields")?;
+        match file.read(nt_offset as u128, 4) {
+            Some(signature) => { if signature != PE_SIGNATURE { return Err(PeError::MissingSignature{ offset: nt_offset }); } }
+            None            => { return Err(PeError::Truncated{ what: "NT headers" }); }
+        }
+
+        // Read the file header
+        let what = "NT headers";
+        let file_header      = nt_offset as u128 + 4;
+        let n_sections       = uint(file, file_header + 2, 2, what)?;
+        let optional_size    = uint(file, file_header + 16, 2, what)?;
+
+        // Read the optional header, where only the image base differs between PE32 and PE32+
+        let optional_header  = file_header + 20;
+        let magic = uint(file, optional_header, 2, what)?;
+        let (is_64, image_base) = match magic {
+            PE32_MAGIC      => (false, uint(file, optional_header + 28, 4, what)?),
+            PE32_PLUS_MAGIC => (true, uint(file, optional_header + 24, 8, what)?),
+            magic           => { return Err(PeError::UnknownMagic{ magic }); }
+        };
+        let size_of_headers = uint(file, optional_header + 60, 4, what)?;
+
+        // Read the section headers, which directly follow the optional header
+        let what = "section headers";
+        let mut sections: Vec<PeSection> = Vec::with_capacity(n_sections as usize);
+        for i in 0..n_sections {
+            let base = optional_header + optional_size as u128 + i as u128 * SECTION_HEADER_SIZE;
+            let name = file.read(base, 8).ok_or(PeError::Truncated{ what })?;
+            let name = &name[..name.iter().position(|b| *b == 0).unwrap_or(8)];
+            sections.push(PeSection {
+                name         : String::from_utf8_lossy(name).into_owned(),
+                virtual_size : uint(file, base + 8, 4, what)?,
+                rva          : uint(file, base + 12, 4, what)?,
+                raw_size     : uint(file, base + 16, 4, what)?,
+                raw          : uint(file, base + 20, 4, what)?,
+            });
+        }
+
+        // Done
+        return Ok(Pe {
+            is_64,
+            image_base,
+            size_of_headers,
+            sections,
+        });
+    }
+
+
+
+    /// Finds the section that contains the given RVA in memory (which includes the part that's not in the file, like uninitialized data).
+    ///
+    /// **Arguments**
+    ///  * `rva`: The RVA to look for.
+    ///
+    /// **Returns**
+    /// The index and the section of the first section containing the RVA, or None if there is no such section.
+    pub fn section_at_rva(&self, rva: u64) -> Option<(usize, &PeSection)> {
+        return self.sections.iter().enumerate().find(|(_, section)| rva >= section.rva && rva - section.rva < section.size());
+    }
+
+    /// Finds the section that contains the given offset in the file, where the alignment padding at the end of its raw data (past its size in memory) is not part of it.
+    ///
+    /// **Arguments**
+    ///  * `offset`: The file offset to look for.
+    ///
+    /// **Returns**
+    /// The index and the section of the first section containing the offset, or None if there is no such section.
+    pub fn section_at_offset(&self, offset: u64) -> Option<(usize, &PeSection)> {
+        return self.sections.iter().enumerate().find(|(_, section)| offset >= section.raw && offset - section.raw < section.raw_size.min(section.size()));
+    }
+
+
+
+    /// Returns the variables that describe the sections of this file.
+    ///
+    /// Every named section 'X' defines 'X.rva', 'X.raw' and 'X.size' (its size in memory), where the name is converted to an identifier like ELF section names are (e.g., '.text' becomes 'text').
+    ///
+    /// **Returns**
+    /// A list of (name, value) pairs for every variable.
+    pub fn variables(&self) -> Vec<(String, u64)> {
+        let mut result: Vec<(String, u64)> = Vec::with_capacity(3 * self.sections.len());
+        for section in self.sections.iter() {
+            let name = identifier(&section.name);
+            if name.is_empty() { continue; }
+            result.push((format!("{}.rva", name), section.rva));
+            result.push((format!("{}.raw", name), section.raw));
+            result.push((format!("{}.size", name), section.size()));
+        }
+        return result;
+    }
+}
+
+
+
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::builtins::translate_pe;
+    use crate::ast::builtins::BuiltinError;
+
+    /// Opens the fixture with the given name from 'tests/fixtures'.
+    ///
+    /// Both fixtures have 0x200 bytes of headers, followed by the same three sections:
+    ///  * '.text' at RVA 0x1000 with 0x180 bytes in memory, and 0x200 bytes at offset 0x200 in the file (so the last 0x80 are padding).
+    ///  * '.data' at RVA 0x2000 with 0x300 bytes in memory, but only 0x100 bytes at offset 0x400 in the file.
+    ///  * '.bss' at RVA 0x3000 with 0x80 bytes in memory, and none in the file.
+    fn fixture(name: &str) -> BinaryFile {
+        return BinaryFile::open(&format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap();
+    }
+
+    /// Checks that the sections of the fixtures are parsed as described above.
+    fn check_sections(pe: &Pe) {
+        let sections: Vec<(&str, u64, u64, u64)> = pe.sections.iter().map(|section| (section.name.as_str(), section.rva, section.raw, section.size())).collect();
+        assert_eq!(sections, vec![
+            (".text", 0x1000, 0x200, 0x180),
+            (".data", 0x2000, 0x400, 0x300),
+            (".bss",  0x3000, 0x000, 0x080),
+        ]);
+    }
+
+
+
+    #[test]
+    fn parses_pe32() {
+        let file = fixture("pe32.exe");
+        let pe = file.pe().unwrap();
+        assert!(!pe.is_64);
+        assert_eq!(pe.image_base, 0x400000);
+        assert_eq!(pe.size_of_headers, 0x200);
+        check_sections(pe);
+    }
+
+    #[test]
+    fn parses_pe32_plus() {
+        let file = fixture("pe64.exe");
+        let pe = file.pe().unwrap();
+        assert!(pe.is_64);
+        assert_eq!(pe.image_base, 0x140000000);
+        assert_eq!(pe.size_of_headers, 0x200);
+        check_sections(pe);
+    }
+
+    #[test]
+    fn defines_section_variables() {
+        let file = fixture("pe32.exe");
+        let variables = file.pe().unwrap().variables();
+        assert!(variables.contains(&(String::from("text.rva"), 0x1000)));
+        assert!(variables.contains(&(String::from("data.raw"), 0x400)));
+        assert!(variables.contains(&(String::from("bss.size"), 0x80)));
+        assert_eq!(variables.len(), 9);
+    }
+
+
+
+    #[test]
+    fn translates_rvas_and_offsets() {
+        for name in ["pe32.exe", "pe64.exe"] {
+            let file = fixture(name);
+            assert_eq!(translate_pe(Some(&file), 0x1000, true, false).unwrap(), (0x200, None));
+            assert_eq!(translate_pe(Some(&file), 0x2010, true, false).unwrap(), (0x410, None));
+            assert_eq!(translate_pe(Some(&file), 0x200, false, false).unwrap(), (0x1000, None));
+            assert_eq!(translate_pe(Some(&file), 0x4FF, false, false).unwrap(), (0x20FF, None));
+
+            // Round-trips through the same section
+            let (offset, _) = translate_pe(Some(&file), 0x117F, true, false).unwrap();
+            assert_eq!(translate_pe(Some(&file), offset, false, false).unwrap(), (0x117F, None));
+        }
+    }
+
+    #[test]
+    fn passes_headers_through() {
+        let file = fixture("pe32.exe");
+        assert_eq!(translate_pe(Some(&file), 0x0, true, false).unwrap(), (0x0, None));
+        assert_eq!(translate_pe(Some(&file), 0x3C, true, false).unwrap(), (0x3C, None));
+        assert_eq!(translate_pe(Some(&file), 0x1FF, true, false).unwrap(), (0x1FF, None));
+        assert_eq!(translate_pe(Some(&file), 0x1FF, false, false).unwrap(), (0x1FF, None));
+    }
+
+    #[test]
+    fn notes_uninitialized_data() {
+        let file = fixture("pe64.exe");
+
+        // Past the raw data of '.data', but still within its size in memory
+        let (offset, note) = translate_pe(Some(&file), 0x2100, true, false).unwrap();
+        assert_eq!(offset, 0x500);
+        assert!(note.unwrap().contains("uninitialized part of section 1"));
+
+        // '.bss' has no raw data at all, so it has no offset either
+        assert!(matches!(translate_pe(Some(&file), 0x3010, true, false), Err(BuiltinError::NoFileData{ section: 2 })));
+    }
+
+    #[test]
+    fn rejects_unmapped_values() {
+        let file = fixture("pe32.exe");
+
+        // Past the end of '.text' in memory, past the end of the image and in the padding of '.text' in the file
+        assert!(translate_pe(Some(&file), 0x1180, true, false).is_err());
+        assert!(translate_pe(Some(&file), 0x4000, true, false).is_err());
+        assert!(translate_pe(Some(&file), 0x380, false, false).is_err());
+        assert!(translate_pe(Some(&file), u128::MAX, true, true).is_err());
+        assert!(translate_pe(None, 0x1000, true, false).is_err());
+    }
+}
@@ -18,7 +18,9 @@ use std::fs::File;
 use rustyline::Editor;
 
 use crate::ast::symbol_table::SymbolTable;
+use crate::ast::symbol_table::Variable;
 use crate::ast::parser::ValueKind;
+use crate::ast::parser::ValueWidth;
 
 
 /***** HELPER ENUMS *****/
@@ -32,6 +34,8 @@ enum ReaderState {
     History,
     /// We're reading SymbolTable lines.
     SymbolTable,
+    /// We're reading the path of the opened file.
+    File,
 }
 
 
@@ -271,10 +275,11 @@ impl std::error::Error for SessionError {}
 ///  * `path`: The path to load from.
 ///  * `symbol_table`: The SymbolTable to populate with the saved variables.
 ///  * `rl`: The RustyLine editor that will be populated with the history lines.
+///  * `file_path`: Will be set to the path of the file that was opened in the session, if any.
 /// 
 /// **Returns**  
 /// Returns Ok() when everything went right, or err() with the reason when it didn't.
-pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) -> Result<(), SessionError> {
+pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>, file_path: &mut Option<String>) -> Result<(), SessionError> {
     // Start by trying to open the file
     let openres = File::open(path);
     if let Err(reason) = openres {
@@ -303,11 +308,13 @@ pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) ->
                     continue;
                 }
 
-                // We only accept '[history]' and '[symtable]' lines
+                // We only accept '[history]', '[symtable]' and '[file]' lines
                 if line.to_lowercase().eq("[history]") {
                     state = ReaderState::History;
                 } else if line.to_lowercase().eq("[symtable]") {
                     state = ReaderState::SymbolTable;
+                } else if line.to_lowercase().eq("[file]") {
+                    state = ReaderState::File;
                 }
             }
 
@@ -330,6 +337,11 @@ pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) ->
                     state = ReaderState::SymbolTable;
                     l += 1;
                     continue;
+                } else if line.to_lowercase().eq("[file]") {
+                    // Move to parsing the file
+                    state = ReaderState::File;
+                    l += 1;
+                    continue;
                 }
 
                 // Unescape the history string
@@ -365,9 +377,14 @@ pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) ->
                     // Ignore, as we're already in symtable
                     l += 1;
                     continue;
+                } else if line.to_lowercase().eq("[file]") {
+                    // Move to parsing the file
+                    state = ReaderState::File;
+                    l += 1;
+                    continue;
                 }
 
-                // Lines are in the format 'ID=KIND,VALUE', so split on that
+                // Lines are in the format 'ID=KIND,VALUE[,SIGNEDNESS[,WIDTH[,END]]]', so split on that
                 let eq_parts = split(&line, '=');
                 if eq_parts.len() != 2 {
                     eprintln!("WARNING: Expected one equal sign, got {} on line {} of session file '{}'; skipping line.", eq_parts.len(), l, path);
@@ -375,10 +392,10 @@ pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) ->
                     continue;
                 }
 
-                // The first is the ID, so split the second on the comma
+                // The first is the ID, so split the second on the comma (the signedness and width are optional for older session files, and the end only exists for ranges)
                 let comma_parts = split(eq_parts[1], ',');
-                if comma_parts.len() != 2 {
-                    eprintln!("WARNING: Expected one comma after equal sign, got {} on line {} of session file '{}'; skipping line.", comma_parts.len(), l, path);
+                if comma_parts.len() < 2 || comma_parts.len() > 5 {
+                    eprintln!("WARNING: Expected one to four commas after equal sign, got {} on line {} of session file '{}'; skipping line.", comma_parts.len(), l, path);
                     l += 1;
                     continue;
                 }
@@ -395,17 +412,98 @@ pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) ->
 
                 // Then, parse the other as a value
                 let rvalue = strip(comma_parts[1]);
-                let ivalue = rvalue.parse::<u64>();
+                let ivalue = rvalue.parse::<u128>();
                 if ivalue.is_err() {
-                    eprintln!("WARNING: Cannot parse '{}' as u64 on line {} of session file '{}': {}.", rvalue, l, path, ivalue.err().unwrap());
+                    eprintln!("WARNING: Cannot parse '{}' as u128 on line {} of session file '{}': {}.", rvalue, l, path, ivalue.err().unwrap());
                     eprintln!("Skipping line.");
                     l += 1;
                     continue;
                 }
                 let value = ivalue.ok().unwrap();
 
-                // We did it! Add the symbol table entry
-                symbol_table.insert(strip(eq_parts[0]), (kind, value));
+                // Finally, parse the signedness if there is any
+                let mut signed = false;
+                if comma_parts.len() >= 3 {
+                    let rsigned = strip(comma_parts[2]);
+                    if rsigned.to_lowercase().eq("signed") {
+                        signed = true;
+                    } else if !rsigned.to_lowercase().eq("unsigned") {
+                        eprintln!("WARNING: Unknown signedness '{}' on line {} of session file '{}'; skipping line.", rsigned, l, path);
+                        l += 1;
+                        continue;
+                    }
+                }
+
+                // Then, parse the width if there is any (older session files are always 64-bit)
+                let mut width = ValueWidth::U64;
+                if comma_parts.len() >= 4 {
+                    let rwidth = strip(comma_parts[3]);
+                    match rwidth.parse::<ValueWidth>() {
+                        Ok(ValueWidth::Undefined) | Err(_) => {
+                            eprintln!("WARNING: Unknown width '{}' on line {} of session file '{}'; skipping line.", rwidth, l, path);
+                            l += 1;
+                            continue;
+                        }
+                        Ok(val) => { width = val; }
+                    }
+                }
+
+                // Finally, parse the end if it's a range
+                let mut end = None;
+                if comma_parts.len() == 5 {
+                    let rend = strip(comma_parts[4]);
+                    match rend.parse::<u128>() {
+                        Ok(val) => { end = Some(width.wrap(val, signed)); }
+                        Err(reason) => {
+                            eprintln!("WARNING: Cannot parse '{}' as u128 on line {} of session file '{}': {}.", rend, l, path, reason);
+                            eprintln!("Skipping line.");
+                            l += 1;
+                            continue;
+                        }
+                    }
+                }
+
+                // We did it! Add the symbol table entry (with the value as its two's complement if needed)
+                symbol_table.insert(strip(eq_parts[0]), Variable::new(kind, width.wrap(value, signed), signed, width, end));
+            }
+
+            ReaderState::File => {
+                // Strip the line
+                let line = strip(&line);
+                if line.len() == 0 {
+                    // Skip line
+                    l += 1;
+                    continue;
+                }
+
+                // Handle special lines
+                if line.to_lowercase().eq("[history]") {
+                    // Move to parsing history
+                    state = ReaderState::History;
+                    l += 1;
+                    continue;
+                } else if line.to_lowercase().eq("[symtable]") {
+                    // Move to parsing symbol tables
+                    state = ReaderState::SymbolTable;
+                    l += 1;
+                    continue;
+                } else if line.to_lowercase().eq("[file]") {
+                    // Ignore, as we're already in file
+                    l += 1;
+                    continue;
+                }
+
+                // Unescape the path, which is stored just like history lines
+                let iunescaped = unescape(&line);
+                if iunescaped.is_err() {
+                    let e = iunescaped.err().unwrap();
+                    eprintln!("{}:{}:{}: WARNING: {}; skipping line.", path, l, e.pos(), e);
+                    l += 1;
+                    continue;
+                }
+
+                // Remember it as the file to open
+                *file_path = Some(iunescaped.ok().unwrap());
             }
         }
 
@@ -423,10 +521,11 @@ pub fn load(path: &str, symbol_table: &mut SymbolTable, rl: &mut Editor<()>) ->
 ///  * `path`: The path to save to.
 ///  * `symbol_table`: The SymbolTable to save.
 ///  * `rl`: The RustyLine editor with the history to save.
+///  * `file_path`: The path of the file that is opened in the session, if any.
 /// 
 /// **Returns**  
 /// Returns Ok() when everything went right, or err() with the reason when it didn't.
-pub fn save(path: &str, symbol_table: &SymbolTable, rl: &Editor<()>) -> Result<(), SessionError> {
+pub fn save(path: &str, symbol_table: &SymbolTable, rl: &Editor<()>, file_path: Option<&str>) -> Result<(), SessionError> {
     // Start by trying to create the file
     let createres = File::create(path);
     if createres.is_err() {
@@ -436,7 +535,7 @@ pub fn save(path: &str, symbol_table: &SymbolTable, rl: &Editor<()>) -> Result<(
     let mut file = createres.ok().unwrap();
 
     // First, write a header
-    let writeres = write!(file, "SESSION FILE for OFFSETCALCULATOR\n   Generated by the OffsetCalculator\n\nThe file is split into two sections:\n - [history]: Stores all lines of the history in a session\n - [symtable]: Stores are variables.\nBefore a section is defined, the parses ignores anything, hence we can write this prelude!\n\n");
+    let writeres = write!(file, "SESSION FILE for OFFSETCALCULATOR\n   Generated by the OffsetCalculator\n\nThe file is split into three sections:\n - [history]: Stores all lines of the history in a session\n - [symtable]: Stores are variables.\n - [file]: Stores the path of the opened file, if any.\nBefore a section is defined, the parses ignores anything, hence we can write this prelude!\n\n");
     if let Err(reason) = writeres {
         return Err(SessionError::WriteError{ path: String::from(path), error: reason })
     }
@@ -459,9 +558,18 @@ pub fn save(path: &str, symbol_table: &SymbolTable, rl: &Editor<()>) -> Result<(
     if let Err(reason) = write!(file, "\n[symtable]\n") {
         return Err(SessionError::WriteError{ path: String::from(path), error: reason })
     }
-    for (identifier, (kind, value)) in symbol_table.iter() {
-        // Write it to the file
-        if let Err(reason) = write!(file, "{} = {:?}, {}\n", identifier, kind, value) {
+    for (identifier, Variable{ kind, value, signed, width, end }) in symbol_table.iter() {
+        // Write it to the file, with the value (and the end of ranges) as its raw bits
+        let mask = width.or(ValueWidth::U64).mask();
+        let end = if let Some(end) = end { format!(", {}", end & mask) } else { String::new() };
+        if let Err(reason) = write!(file, "{} = {:?}, {}, {}, {}{}\n", identifier, kind, value & mask, if *signed { "signed" } else { "unsigned" }, width.or(ValueWidth::U64), end) {
+            return Err(SessionError::WriteError{ path: String::from(path), error: reason })
+        }
+    }
+
+    // Finally, write the opened file (escaped just like the history)
+    if let Some(file_path) = file_path {
+        if let Err(reason) = write!(file, "\n[file]\n{}\n", escape(file_path)) {
             return Err(SessionError::WriteError{ path: String::from(path), error: reason })
         }
     }
@@ -0,0 +1,201 @@
+/* SETTINGS.rs
+ *   by Lut99
+ *
+ * Created:
+ *   17 Oct 2026, 10:12:41
+ * Last edited:
+ *   17 Oct 2026, 10:12:41
+ * Auto updated?
+ *   Yes
+ *
+ * Description:
+ *   Contains the settings of the calculator that can be changed at runtime
+ *   using the 'set' command.
+**/
+
+use crate::ast::parser::ValueWidth;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when changing a setting.
+#[derive(Debug)]
+pub enum SettingsError {
+    /// The given setting does not exist
+    UnknownSettingError{ name: String },
+    /// The given value is not valid for the given setting
+    IllegalValueError{ name: String, value: String, expected: &'static str },
+}
+
+impl std::fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::UnknownSettingError{ name }                => { write!(f, "Unknown setting '{}'", name) }
+            SettingsError::IllegalValueError{ name, value, expected } => { write!(f, "Illegal value '{}' for setting '{}' (expected {})", value, name, expected) }
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Parses a boolean setting value.
+///
+/// **Arguments**
+///  * `value`: The raw value as given by the user.
+///
+/// **Returns**
+/// The parsed boolean, or None if the value is not a boolean.
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "on" | "true" | "yes" | "1"  => { Some(true) }
+        "off" | "false" | "no" | "0" => { Some(false) }
+        _                            => { None }
+    }
+}
+
+
+
+
+
+/***** LIBRARY ENUMS *****/
+/// Defines in which byte orders the bytes of results are shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ByteDisplay {
+    /// The bytes are not shown
+    Off,
+    /// The bytes are shown in little-endian order
+    Little,
+    /// The bytes are shown in big-endian order
+    Big,
+    /// The bytes are shown in both orders
+    Both,
+}
+
+
+
+
+
+/***** LIBRARY STRUCTS *****/
+/// Collects the settings of the calculator.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// If true, constants are interpreted as signed (two's complement) values.
+    pub signed: bool,
+    /// The width of values for which no width is given.
+    pub width: ValueWidth,
+    /// If true, values wrap around when they overflow their width instead of throwing an error.
+    pub wrap: bool,
+    /// If true, hexadecimal and binary values are zero-padded to their width when printed.
+    pub pad: bool,
+    /// If true, the digits of values are grouped with underscores when printed.
+    pub group: bool,
+    /// If true, character literals are interpreted in little-endian byte order instead of big-endian.
+    pub little_endian: bool,
+    /// If true, booleans are printed as 'true' or 'false' instead of '1' or '0'.
+    pub bool_text: bool,
+    /// If true, the results of all statements on a line are printed instead of only the last one.
+    pub print_all: bool,
+    /// Determines in which byte order(s) the bytes of results are shown next to them.
+    pub bytes: ByteDisplay,
+}
+
+impl Settings {
+    /// Constructor for the Settings, which initializes them to their default values.
+    pub fn new() -> Self {
+        Settings {
+            signed: false,
+            width: ValueWidth::U64,
+            wrap: false,
+            pad: false,
+            group: false,
+            little_endian: false,
+            bool_text: true,
+            print_all: false,
+            bytes: ByteDisplay::Off,
+        }
+    }
+
+
+
+    /// Changes the setting with the given name to the given value.
+    ///
+    /// **Arguments**
+    ///  * `name`: The name of the setting to change.
+    ///  * `value`: The new value of the setting, as given by the user.
+    ///
+    /// **Returns**
+    /// Nothing on success, or a SettingsError if the setting does not exist or the value is invalid.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), SettingsError> {
+        match name {
+            "signed" => {
+                match parse_bool(value) {
+                    Some(signed) => { self.signed = signed; }
+                    None         => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "on or off" }); }
+                }
+            }
+
+            "width" => {
+                match value.parse::<ValueWidth>() {
+                    Ok(ValueWidth::Undefined) | Err(_) => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "8, 16, 32, 64 or 128" }); }
+                    Ok(width)                          => { self.width = width; }
+                }
+            }
+            "overflow" => {
+                match value.to_lowercase().as_str() {
+                    "check" => { self.wrap = false; }
+                    "wrap"  => { self.wrap = true; }
+                    _       => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "check or wrap" }); }
+                }
+            }
+            "pad" => {
+                match parse_bool(value) {
+                    Some(pad) => { self.pad = pad; }
+                    None      => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "on or off" }); }
+                }
+            }
+            "group" => {
+                match parse_bool(value) {
+                    Some(group) => { self.group = group; }
+                    None        => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "on or off" }); }
+                }
+            }
+            "endian" => {
+                match value.to_lowercase().as_str() {
+                    "big"    => { self.little_endian = false; }
+                    "little" => { self.little_endian = true; }
+                    _        => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "big or little" }); }
+                }
+            }
+            "boolean" => {
+                match value.to_lowercase().as_str() {
+                    "text"   => { self.bool_text = true; }
+                    "number" => { self.bool_text = false; }
+                    _        => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "text or number" }); }
+                }
+            }
+            "print" => {
+                match value.to_lowercase().as_str() {
+                    "last" => { self.print_all = false; }
+                    "all"  => { self.print_all = true; }
+                    _      => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "last or all" }); }
+                }
+            }
+            "bytes" => {
+                match value.to_lowercase().as_str() {
+                    "off"  => { self.bytes = ByteDisplay::Off; }
+                    "le"   => { self.bytes = ByteDisplay::Little; }
+                    "be"   => { self.bytes = ByteDisplay::Big; }
+                    "both" => { self.bytes = ByteDisplay::Both; }
+                    _      => { return Err(SettingsError::IllegalValueError{ name: String::from(name), value: String::from(value), expected: "off, le, be or both" }); }
+                }
+            }
+
+            _ => { return Err(SettingsError::UnknownSettingError{ name: String::from(name) }); }
+        }
+
+        // Done
+        return Ok(());
+    }
+}
@@ -12,10 +12,124 @@
  *   Traverses the tree and performs the computations!
 **/
 
+use crate::settings::Settings;
 use crate::ast::symbol_table::SymbolTable;
+use crate::ast::builtins;
+use crate::ast::builtins::BuiltinFunc;
+use crate::ast::builtins::Range;
+use crate::ast::parser::ValueKind;
+use crate::ast::parser::ValueWidth;
 use crate::ast::parser::LowBinaryOperator;
 use crate::ast::parser::HighBinaryOperator;
+use crate::ast::parser::ShiftOperator;
+use crate::ast::parser::BitwiseBinaryOperator;
+use crate::ast::parser::ComparisonOperator;
+use crate::ast::parser::UnaryOperator;
+use crate::ast::parser::AssignOperator;
 use crate::ast::parser::ASTNode;
+use crate::ast::parser::get_signed;
+use crate::ast::parser::get_range;
+use crate::ast::parser::get_float;
+use crate::ast::parser::get_const;
+use crate::ast::symbols::Symbol;
+use crate::binary::BinaryFile;
+
+
+/***** HELPER MACROS *****/
+/// Performs the given overflowing operation on two values, interpreting them as either signed or unsigned 128-bit integers.
+/// 
+/// **Arguments**
+///  * `left`: The lefthand-side value.
+///  * `right`: The righthand-side value.
+///  * `signed`: Whether to interpret the values as signed integers.
+///  * `op`: The name of the overflowing_* function to call.
+/// 
+/// **Returns**  
+/// The (wrapped) result of the operation and whether it overflowed the 128-bits, as a tuple (in that order).
+macro_rules! overflowing_op {
+    ($left: expr, $right: expr, $signed: expr, $op: ident) => {
+        if $signed {
+            let (result, overflow) = ($left as i128).$op($right as i128);
+            (result as u128, overflow)
+        } else {
+            $left.$op($right)
+        }
+    };
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Formats the given value as a decimal string, interpreting it as a two's complement value if it's signed.
+/// 
+/// **Arguments**
+///  * `value`: The raw value to format.
+///  * `signed`: Whether the value should be interpreted as a signed value.
+/// 
+/// **Returns**  
+/// The value as a decimal string.
+fn as_string(value: u128, signed: bool) -> String {
+    if signed { (value as i128).to_string() } else { value.to_string() }
+}
+
+/// Fits the result of an operation in the given width, either by wrapping it or by checking it.
+/// 
+/// **Arguments**
+///  * `value`: The result of the operation.
+///  * `overflow`: Whether the operation already overflowed the 128-bits.
+///  * `width`: The width that the result should fit in.
+///  * `signed`: Whether the result is a signed value.
+///  * `settings`: The Settings that determine whether we wrap or check.
+/// 
+/// **Returns**  
+/// The fitted value, or None if it overflowed while we should check.
+fn fit(value: u128, overflow: bool, width: ValueWidth, signed: bool, settings: &Settings) -> Option<u128> {
+    // If we wrap, simply throw away the bits that don't fit
+    if settings.wrap { return Some(width.wrap(value, signed)); }
+
+    // Otherwise, check it
+    if overflow || !width.fits(value, signed) { return None; }
+    return Some(value);
+}
+
+/// Formats a call to a built-in function for use in error messages.
+/// 
+/// **Arguments**
+///  * `identifier`: The name of the function.
+///  * `arg_vals`: The values of the arguments, if the function takes values.
+///  * `arg_ranges`: The ranges of the arguments, if the function takes ranges.
+///  * `signed`: Whether the arguments should be interpreted as signed values.
+/// 
+/// **Returns**  
+/// The call as a String (e.g., 'min(1, 2)' or 'len(0..16)').
+fn call_string(identifier: &str, arg_vals: &[u128], arg_ranges: &[Range], signed: bool) -> String {
+    let args = arg_vals.iter().map(|v| as_string(*v, signed))
+        .chain(arg_ranges.iter().map(|(start, end)| format!("{}..{}", as_string(*start, signed), as_string(*end, signed))))
+        .collect::<Vec<String>>();
+    return format!("{}({})", identifier, args.join(", "));
+}
+
+/// Converts a value to the given width, like fit(), except that unsigned bit patterns (hexadecimal, binary or octal values) that fit the width are reinterpreted as two's complement if the result is signed.
+/// 
+/// **Arguments**
+///  * `value`: The value to convert.
+///  * `kind`: The kind of the value, which determines whether it is a bit pattern.
+///  * `width`: The width that the result should fit in.
+///  * `signed`: Whether the result is a signed value.
+///  * `settings`: The Settings that determine whether we wrap or check.
+/// 
+/// **Returns**  
+/// The converted value, or None if it overflowed while we should check.
+fn convert(value: u128, kind: ValueKind, width: ValueWidth, signed: bool, settings: &Settings) -> Option<u128> {
+    // Reinterpret the bits if that's what the user means
+    if signed && kind.is_bit_pattern() && width.fits(value, false) { return Some(width.wrap(value, true)); }
+    return fit(value, false, width, signed, settings);
+}
+
+
+
 
 
 /***** NODE FUNCTIONS *****/
@@ -25,57 +139,124 @@ use crate::ast::parser::ASTNode;
 ///  * `node`: The node to traverse.
 ///  * `value`: The intermediate value on which we operate.
 ///  * `symtable`: The symbol table that we use to keep track of identifiers.
+///  * `settings`: The Settings that determine the default width and what to do on overflow.
+///  * `file`: The opened file that functions read from, if any.
 ///  * `error`: Can be set to indicate an error has occurred.
 /// 
 /// **Returns**  
 /// The given node, or else a replacement if deemed necessary.
-fn traverse_node(mut node: ASTNode, value: &mut u64, symtable: &mut SymbolTable, error: &mut bool) -> ASTNode {
+fn traverse_node(mut node: ASTNode, value: &mut u128, symtable: &mut SymbolTable, settings: &Settings, file: Option<&BinaryFile>, error: &mut bool) -> ASTNode {
     // Switch on the node
     match node {
-        ASTNode::Expr{ override_kind: _, kind: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Expr{ override_kind: _, kind, signed, width, range: _, ref mut expr, pos1, pos2: _ } => {
             // Traverse to resolve the child's value
-            **expr = traverse_node(*expr.clone(), value, symtable, error);
+            let mut child_val: u128 = 0;
+            **expr = traverse_node(*expr.clone(), &mut child_val, symtable, settings, file, error);
+
+            // Make sure it fits in the width of the expression
+            let width = width.or(settings.width);
+            match convert(child_val, kind, width, signed, settings) {
+                Some(val) => {
+                    *value = val;
+                }
+                None => {
+                    // Overflow
+                    eprintln!("   {}: Overflow occurred while fitting {} in {}.", pos1, as_string(child_val, signed), width);
+                    *error = true;
+                }
+            }
         }
 
-        ASTNode::Assign{ override_kind: _, kind: _, ref identifier, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Assign{ override_kind: _, kind: _, signed, width, operator, ref identifier, ref mut expr, pos1, pos2: _ } => {
             // Traverse to resolve the child's value
-            **expr = traverse_node(*expr.clone(), value, symtable, error);
+            let mut child_val: u128 = 0;
+            **expr = traverse_node(*expr.clone(), &mut child_val, symtable, settings, file, error);
+
+            // Compound assignments first apply their operation to the variable's current value
+            let var_val = symtable.get(identifier).unwrap().value;
+            let (result, overflow, sign) = match operator {
+                AssignOperator::Assign => { (child_val, false, "=") }
+                AssignOperator::Plus => {
+                    let (result, overflow) = overflowing_op!(var_val, child_val, signed, overflowing_add);
+                    (result, overflow, "+=")
+                }
+                AssignOperator::Minus => {
+                    let (result, overflow) = overflowing_op!(var_val, child_val, signed, overflowing_sub);
+                    (result, overflow, "-=")
+                }
+                AssignOperator::Multiply => {
+                    let (result, overflow) = overflowing_op!(var_val, child_val, signed, overflowing_mul);
+                    (result, overflow, "*=")
+                }
+                AssignOperator::Divide => {
+                    // We can never divide by zero, even when wrapping
+                    if child_val == 0 {
+                        eprintln!("   {}: Division by zero while performing {} /= {}.", pos1, identifier, as_string(child_val, signed));
+                        *error = true;
+                        return node;
+                    }
+                    let (result, overflow) = overflowing_op!(var_val, child_val, signed, overflowing_div);
+                    (result, overflow, "/=")
+                }
+                AssignOperator::Undefined => {
+                    panic!("Encountered an undefined assign at pos {}: this should never happen!", pos1);
+                }
+            };
+
+            // Plain assignments are already fitted by their child, but compound ones have to fit in the variable
+            if operator == AssignOperator::Assign {
+                *value = result;
+            } else {
+                let width = width.or(settings.width);
+                match fit(result, overflow, width, signed, settings) {
+                    Some(val) => {
+                        *value = val;
+                    }
+                    None => {
+                        // Overflow
+                        eprintln!("   {}: Overflow occurred while performing {} {} {} (with {} = {}).", pos1, identifier, sign, as_string(child_val, signed), identifier, as_string(var_val, signed));
+                        *error = true;
+                        return node;
+                    }
+                }
+            }
 
             // Also push the update to the symbol table
-            symtable.get_mut(identifier).unwrap().1 = *value;
+            symtable.get_mut(identifier).unwrap().value = *value;
         }
-        ASTNode::BinOpLow{ override_kind: _, kind: _, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
+        ASTNode::BinOpLow{ override_kind: _, kind: _, signed, width, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
             // Traverse to resolve the children's value
-            let mut left_val: u64 = 0; let mut right_val: u64 = 0;
-            **left  = traverse_node(*left.clone(), &mut left_val, symtable, error);
-            **right = traverse_node(*right.clone(), &mut right_val, symtable, error);
+            let mut left_val: u128 = 0; let mut right_val: u128 = 0;
+            **left  = traverse_node(*left.clone(), &mut left_val, symtable, settings, file, error);
+            **right = traverse_node(*right.clone(), &mut right_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
 
             // Switch on the operator type
             match operator {
                 LowBinaryOperator::Plus => {
                     // Try to add the values
-                    let result = left_val.checked_add(right_val);
-                    match result {
+                    let (result, overflow) = overflowing_op!(left_val, right_val, signed, overflowing_add);
+                    match fit(result, overflow, width, signed, settings) {
                         Some(val) => {
                             *value = val;
                         }
                         None => {
                             // Overflow
-                            eprintln!("   {}: Overflow occurred while performing {} + {}.", pos1, left_val, right_val);
+                            eprintln!("   {}: Overflow occurred while performing {} + {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
                             *error = true;
                         }
                     }
                 }
                 LowBinaryOperator::Minus => {
                     // Try to subtract the values
-                    let result = left_val.checked_sub(right_val);
-                    match result {
+                    let (result, overflow) = overflowing_op!(left_val, right_val, signed, overflowing_sub);
+                    match fit(result, overflow, width, signed, settings) {
                         Some(val) => {
                             *value = val;
                         }
                         None => {
                             // Overflow
-                            eprintln!("   {}: Overflow occurred while performing {} - {}.", pos1, left_val, right_val);
+                            eprintln!("   {}: Overflow occurred while performing {} - {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
                             *error = true;
                         }
                     }
@@ -85,38 +266,103 @@ fn traverse_node(mut node: ASTNode, value: &mut u64, symtable: &mut SymbolTable,
                 }
             }
         }
-        ASTNode::BinOpHigh{ override_kind: _, kind: _, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
+        ASTNode::BinOpHigh{ override_kind: _, kind: _, signed, width, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
             // Traverse to resolve the children's value
-            let mut left_val: u64 = 0; let mut right_val: u64 = 0;
-            **left  = traverse_node(*left.clone(), &mut left_val, symtable, error);
-            **right = traverse_node(*right.clone(), &mut right_val, symtable, error);
+            let mut left_val: u128 = 0; let mut right_val: u128 = 0;
+            **left  = traverse_node(*left.clone(), &mut left_val, symtable, settings, file, error);
+            **right = traverse_node(*right.clone(), &mut right_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
 
             // Switch on the operator type
             match operator {
                 HighBinaryOperator::Multiply => {
-                    // Try to add the values
-                    let result = left_val.checked_mul(right_val);
-                    match result {
+                    // Try to multiply the values
+                    let (result, overflow) = overflowing_op!(left_val, right_val, signed, overflowing_mul);
+                    match fit(result, overflow, width, signed, settings) {
                         Some(val) => {
                             *value = val;
                         }
                         None => {
                             // Overflow
-                            eprintln!("   {}: Overflow occurred while performing {} * {}.", pos1, left_val, right_val);
+                            eprintln!("   {}: Overflow occurred while performing {} * {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
                             *error = true;
                         }
                     }
                 }
                 HighBinaryOperator::Divide => {
-                    // Try to subtract the values
-                    let result = left_val.checked_div(right_val);
-                    match result {
+                    // We can never divide by zero, even when wrapping
+                    if right_val == 0 {
+                        eprintln!("   {}: Division by zero while performing {} / {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                        *error = true;
+                        return node;
+                    }
+
+                    // Try to divide the values, which can only overflow for signed values
+                    let (result, overflow) = overflowing_op!(left_val, right_val, signed, overflowing_div);
+                    match fit(result, overflow, width, signed, settings) {
                         Some(val) => {
                             *value = val;
                         }
                         None => {
                             // Overflow
-                            eprintln!("   {}: Overflow occurred while performing {} / {}.", pos1, left_val, right_val);
+                            eprintln!("   {}: Overflow occurred while performing {} / {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                            *error = true;
+                        }
+                    }
+                }
+                HighBinaryOperator::Modulo => {
+                    // We can never divide by zero, even when wrapping
+                    if right_val == 0 {
+                        eprintln!("   {}: Division by zero while performing {} % {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                        *error = true;
+                        return node;
+                    }
+
+                    // Try to take the remainder of the values, which can only overflow for signed values
+                    let (result, overflow) = overflowing_op!(left_val, right_val, signed, overflowing_rem);
+                    match fit(result, overflow, width, signed, settings) {
+                        Some(val) => {
+                            *value = val;
+                        }
+                        None => {
+                            // Overflow
+                            eprintln!("   {}: Overflow occurred while performing {} % {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                            *error = true;
+                        }
+                    }
+                }
+                HighBinaryOperator::Power => {
+                    // We can never raise to a negative power, even when wrapping
+                    if signed && (right_val as i128) < 0 {
+                        eprintln!("   {}: Negative exponent while performing {} ** {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                        *error = true;
+                        return node;
+                    }
+
+                    // We also don't support exponents beyond 32-bits, even when wrapping
+                    let exp = match u32::try_from(right_val) {
+                        Ok(exp) => exp,
+                        Err(_)  => {
+                            eprintln!("   {}: Overflow occurred while performing {} ** {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                            *error = true;
+                            return node;
+                        }
+                    };
+
+                    // Try to raise the value to the power
+                    let (result, overflow) = if signed {
+                        let (result, overflow) = (left_val as i128).overflowing_pow(exp);
+                        (result as u128, overflow)
+                    } else {
+                        left_val.overflowing_pow(exp)
+                    };
+                    match fit(result, overflow, width, signed, settings) {
+                        Some(val) => {
+                            *value = val;
+                        }
+                        None => {
+                            // Overflow
+                            eprintln!("   {}: Overflow occurred while performing {} ** {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
                             *error = true;
                         }
                     }
@@ -126,20 +372,481 @@ fn traverse_node(mut node: ASTNode, value: &mut u64, symtable: &mut SymbolTable,
                 }
             }
         }
-        ASTNode::MonOp{ kind: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::BinOpShift{ override_kind: _, kind: _, signed, width, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
+            // Traverse to resolve the children's value
+            let mut left_val: u128 = 0; let mut right_val: u128 = 0;
+            **left  = traverse_node(*left.clone(), &mut left_val, symtable, settings, file, error);
+            **right = traverse_node(*right.clone(), &mut right_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
+            let bits = width.bits() as u128;
+
+            // Switch on the operator type
+            match operator {
+                ShiftOperator::Left => {
+                    // Shift the value, which overflows if we shift too far or any bits fall off (or, if signed, the sign changes)
+                    let (result, overflow) = if right_val >= bits {
+                        (0, true)
+                    } else if signed {
+                        let shifted = (left_val as i128) << right_val;
+                        (shifted as u128, shifted >> right_val != left_val as i128)
+                    } else {
+                        let shifted = left_val << right_val;
+                        (shifted, shifted >> right_val != left_val)
+                    };
+                    match fit(result, overflow, width, signed, settings) {
+                        Some(val) => {
+                            *value = val;
+                        }
+                        None => {
+                            // Overflow
+                            eprintln!("   {}: Overflow occurred while performing {} << {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                            *error = true;
+                        }
+                    }
+                }
+                ShiftOperator::Right => {
+                    // Shift the value, which only overflows if we shift too far; signed values are shifted arithmetically
+                    let (result, overflow) = if right_val >= bits {
+                        (if signed && (left_val as i128) < 0 { u128::MAX } else { 0 }, true)
+                    } else if signed {
+                        (((left_val as i128) >> right_val) as u128, false)
+                    } else {
+                        (left_val >> right_val, false)
+                    };
+                    match fit(result, overflow, width, signed, settings) {
+                        Some(val) => {
+                            *value = val;
+                        }
+                        None => {
+                            // Overflow
+                            eprintln!("   {}: Overflow occurred while performing {} >> {}.", pos1, as_string(left_val, signed), as_string(right_val, signed));
+                            *error = true;
+                        }
+                    }
+                }
+                ShiftOperator::Undefined => {
+                    panic!("Encountered an undefined binopshift at pos {}: this should never happen!", pos1);
+                }
+            }
+        }
+        ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed, width, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
+            // Traverse to resolve the children's value
+            let mut left_val: u128 = 0; let mut right_val: u128 = 0;
+            **left  = traverse_node(*left.clone(), &mut left_val, symtable, settings, file, error);
+            **right = traverse_node(*right.clone(), &mut right_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
+
+            // Switch on the operator type; these can only overflow if one of the operands doesn't fit the width
+            let (result, op) = match operator {
+                BitwiseBinaryOperator::And => { (left_val & right_val, "&") }
+                BitwiseBinaryOperator::Xor => { (left_val ^ right_val, "^") }
+                BitwiseBinaryOperator::Or  => { (left_val | right_val, "|") }
+                BitwiseBinaryOperator::Undefined => {
+                    panic!("Encountered an undefined binopbitwise at pos {}: this should never happen!", pos1);
+                }
+            };
+            match fit(result, false, width, signed, settings) {
+                Some(val) => {
+                    *value = val;
+                }
+                None => {
+                    // Overflow
+                    eprintln!("   {}: Overflow occurred while performing {} {} {}.", pos1, as_string(left_val, signed), op, as_string(right_val, signed));
+                    *error = true;
+                }
+            }
+        }
+        ASTNode::BinOpCmp{ override_kind: _, kind: _, signed: _, width: _, operator, ref mut left, ref mut right, pos1, pos2: _ } => {
+            // Traverse to resolve the children's value, where the righthand-side of 'in' is a range
+            let mut left_val: u128 = 0; let mut right_val: u128 = 0; let mut right_end: u128 = 0;
+            **left  = traverse_node(*left.clone(), &mut left_val, symtable, settings, file, error);
+            if operator == ComparisonOperator::In {
+                **right = traverse_range(*right.clone(), &mut right_val, &mut right_end, symtable, settings, file, error);
+            } else {
+                **right = traverse_node(*right.clone(), &mut right_val, symtable, settings, file, error);
+            }
+
+            // Compare the values, as signed values if either of the operands is signed
+            let signed = get_signed(left) || get_signed(right);
+            let ordering = if signed { (left_val as i128).cmp(&(right_val as i128)) } else { left_val.cmp(&right_val) };
+            let result = match operator {
+                ComparisonOperator::Equal        => { ordering.is_eq() }
+                ComparisonOperator::NotEqual     => { ordering.is_ne() }
+                ComparisonOperator::Less         => { ordering.is_lt() }
+                ComparisonOperator::LessEqual    => { ordering.is_le() }
+                ComparisonOperator::Greater      => { ordering.is_gt() }
+                ComparisonOperator::GreaterEqual => { ordering.is_ge() }
+                ComparisonOperator::In           => {
+                    // The value should lie in the half-open range
+                    ordering.is_ge() && if signed { (left_val as i128) < (right_end as i128) } else { left_val < right_end }
+                }
+                ComparisonOperator::Undefined => {
+                    panic!("Encountered an undefined binopcmp at pos {}: this should never happen!", pos1);
+                }
+            };
+            *value = result as u128;
+        }
+        ASTNode::Ternary{ override_kind: _, kind, signed, width, ref mut cond, ref mut if_true, ref mut if_false, pos1, pos2: _ } => {
+            // Traverse to resolve the condition's value
+            let mut cond_val: u128 = 0;
+            **cond = traverse_node(*cond.clone(), &mut cond_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
+
+            // Only resolve the branch that is taken, so the other one can't cause errors (or assignments)
+            let mut branch_val: u128 = 0;
+            if cond_val != 0 {
+                **if_true = traverse_node(*if_true.clone(), &mut branch_val, symtable, settings, file, error);
+            } else {
+                **if_false = traverse_node(*if_false.clone(), &mut branch_val, symtable, settings, file, error);
+            }
+
+            // Make sure it fits in the width of the conditional
+            match convert(branch_val, kind, width, signed, settings) {
+                Some(val) => {
+                    *value = val;
+                }
+                None => {
+                    // Overflow
+                    eprintln!("   {}: Overflow occurred while fitting {} in {}.", pos1, as_string(branch_val, signed), width);
+                    *error = true;
+                }
+            }
+        }
+        ASTNode::UnaryOp{ override_kind: _, kind: _, signed, width, operator, ref mut expr, pos1, pos2: _ } => {
+            // Traverse to resolve the child's value, except that constants are negated as written so the lowest signed value of a width doesn't overflow before it's negated
+            let mut child_val: u128 = 0;
+            match (operator, get_const(expr)) {
+                (UnaryOperator::Negate, Some(magnitude)) => { child_val = magnitude; }
+                _ => { **expr = traverse_node(*expr.clone(), &mut child_val, symtable, settings, file, error); }
+            }
+            let width = width.or(settings.width);
+
+            // Switch on the operator type
+            match operator {
+                UnaryOperator::Not => {
+                    // Only flip the bits within the width
+                    *value = width.wrap(!child_val, signed);
+                }
+                UnaryOperator::Negate => {
+                    // Compute it as a subtraction from zero, so unsigned values can only be negated when we wrap
+                    let (result, overflow) = overflowing_op!(0u128, child_val, signed, overflowing_sub);
+                    match fit(result, overflow, width, signed, settings) {
+                        Some(val) => {
+                            *value = val;
+                        }
+                        None => {
+                            // Overflow
+                            eprintln!("   {}: Overflow occurred while performing -{}.", pos1, as_string(child_val, signed));
+                            *error = true;
+                        }
+                    }
+                }
+                UnaryOperator::Undefined => {
+                    panic!("Encountered an undefined unop at pos {}: this should never happen!", pos1);
+                }
+            }
+        }
+        ASTNode::Call{ override_kind: _, kind: _, signed, width, ref identifier, ref mut args, pos1, pos2: _ } => {
+            // Get the function (which must exist, as the symbol table traversal checked that)
+            let builtin = builtins::get(identifier).unwrap();
+            let width = width.or(settings.width);
+
+            // Traverse to resolve the arguments' values, which are ranges if the function takes those
+            let mut arg_vals: Vec<u128> = Vec::with_capacity(args.len());
+            let mut arg_ranges: Vec<Range> = Vec::with_capacity(args.len());
+            for arg in args.iter_mut() {
+                if builtin.takes_ranges() {
+                    let mut arg_start: u128 = 0; let mut arg_end: u128 = 0;
+                    *arg = traverse_range(arg.clone(), &mut arg_start, &mut arg_end, symtable, settings, file, error);
+                    arg_ranges.push((arg_start, arg_end));
+                } else if get_float(arg).is_some() {
+                    // Float constants are read by the function itself
+                    arg_vals.push(0);
+                } else {
+                    let mut arg_val: u128 = 0;
+                    *arg = traverse_node(arg.clone(), &mut arg_val, symtable, settings, file, error);
+                    arg_vals.push(arg_val);
+                }
+            }
+
+            // Call the function, where the arguments are signed if any of them is (which isn't the same as the call if it results in a boolean)
+            let args_signed = args.iter().any(get_signed);
+            let call_str = call_string(identifier, &arg_vals, &arg_ranges, args_signed);
+            let result = match builtin.func {
+                BuiltinFunc::Value(func)        => { func(&arg_vals, args_signed) }
+                BuiltinFunc::RangeToValue(func) => { func(&arg_ranges, args_signed) }
+                BuiltinFunc::RangeToBool(func)  => { func(&arg_ranges, args_signed).map(|result| result as u128) }
+                BuiltinFunc::FloatToBits(func, _) => {
+                    // Pass float constants as-is, and convert any other argument to a float
+                    let arg_floats: Vec<f64> = args.iter().zip(arg_vals.iter()).map(|(arg, val)| get_float(arg).unwrap_or(if get_signed(arg) { *val as i128 as f64 } else { *val as f64 })).collect();
+                    func(&arg_floats)
+                }
+                BuiltinFunc::Swap(swap_width) => { builtins::bswap(arg_vals[0], swap_width, args_signed) }
+                BuiltinFunc::Read(read_width, little_endian) => { builtins::read(file, arg_vals[0], read_width, little_endian, args_signed) }
+                BuiltinFunc::Elf(to_offset) => {
+                    // Translations that land in the BSS are still done, but come with a note
+                    builtins::translate_elf(file, arg_vals[0], to_offset, args_signed).map(|(result, note)| {
+                        if let Some(note) = note { eprintln!("   {}: {} while performing {}.", pos1, note, call_str); }
+                        result
+                    })
+                }
+                BuiltinFunc::Pe(to_offset) => {
+                    // Same as above, but for the uninitialized part of a section
+                    builtins::translate_pe(file, arg_vals[0], to_offset, args_signed).map(|(result, note)| {
+                        if let Some(note) = note { eprintln!("   {}: {} while performing {}.", pos1, note, call_str); }
+                        result
+                    })
+                }
+                BuiltinFunc::RangeToRange(_)    => {
+                    panic!("Encountered range function '{}' outside of a range at pos {}: this should never happen!", identifier, pos1);
+                }
+            };
+            match result {
+                Ok(result) => {
+                    match fit(result, false, width, signed, settings) {
+                        Some(val) => {
+                            *value = val;
+                        }
+                        None => {
+                            // Overflow
+                            eprintln!("   {}: Overflow occurred while performing {}.", pos1, call_str);
+                            *error = true;
+                        }
+                    }
+                }
+                Err(reason) => {
+                    eprintln!("   {}: {} while performing {}.", pos1, reason, call_str);
+                    *error = true;
+                }
+            }
+        }
+        ASTNode::Cast{ override_kind: _, kind, signed, width, ref mut expr, pos1, pos2: _ } => {
             // Traverse to resolve the child's value
-            **expr = traverse_node(*expr.clone(), value, symtable, error);
+            let mut child_val: u128 = 0;
+            **expr = traverse_node(*expr.clone(), &mut child_val, symtable, settings, file, error);
+
+            // Fit it in the new width
+            match convert(child_val, kind, width, signed, settings) {
+                Some(val) => {
+                    *value = val;
+                }
+                None => {
+                    // Overflow
+                    eprintln!("   {}: Overflow occurred while casting {} to {}.", pos1, as_string(child_val, signed), width);
+                    *error = true;
+                }
+            }
+        }
+        ASTNode::Slice{ override_kind: _, kind: _, signed: _, width, ref mut bits, ref mut expr, ref mut hi, ref mut lo, pos1: _, pos2: _ } => {
+            // Traverse to resolve the value and its bits, where a single bit is both the upper and the lower one
+            let mut child_val: u128 = 0; let mut hi_val: u128 = 0; let mut lo_val: u128 = 0;
+            **expr = traverse_node(*expr.clone(), &mut child_val, symtable, settings, file, error);
+            **hi = traverse_node(*hi.clone(), &mut hi_val, symtable, settings, file, error);
+            let lo_node: &ASTNode = match lo {
+                Some(lo) => {
+                    **lo = traverse_node(*lo.clone(), &mut lo_val, symtable, settings, file, error);
+                    lo
+                }
+                None => {
+                    lo_val = hi_val;
+                    hi
+                }
+            };
+            let bit_infos = [(hi_val, get_signed(hi), hi.pos().0), (lo_val, get_signed(lo_node), lo_node.pos().0)];
+            let width = width.or(settings.width);
+
+            // Both bits should lie within the value (where negative bits are always out of range)
+            for (bit, bit_signed, bit_pos) in bit_infos {
+                if (bit_signed && (bit as i128) < 0) || bit >= width.bits() as u128 {
+                    eprintln!("   {}: Bit {} lies outside of the {}-bit value.", bit_pos, as_string(bit, bit_signed), width.bits());
+                    *error = true;
+                    return node;
+                }
+            }
+            // And they should be given from high to low
+            if hi_val < lo_val {
+                eprintln!("   {}: Reversed slice [{}:{}]; the upper bit should come first (i.e., [{}:{}]).", hi.pos().0, hi_val, lo_val, lo_val, hi_val);
+                *error = true;
+                return node;
+            }
+
+            // Extract the bits and shift them down, only looking at the bits within the value's width
+            *bits = (hi_val - lo_val + 1) as u32;
+            let mask = if *bits >= 128 { u128::MAX } else { (1 << *bits) - 1 };
+            *value = ((child_val & width.mask()) >> lo_val) & mask;
+        }
+        ASTNode::MonOp{ kind: _, signed: _, width: _, ref mut expr, pos1: _, pos2: _ } => {
+            // Traverse to resolve the child's value
+            **expr = traverse_node(*expr.clone(), value, symtable, settings, file, error);
         }
 
         ASTNode::Id{ ref identifier, pos1: _, pos2: _ } => {
             // Fetch the value from the symbol table
-            let (_, sym_value) = symtable.get(identifier).unwrap();
-            *value = *sym_value;
+            *value = symtable.get(identifier).unwrap().value;
         }
         ASTNode::Const{ kind: _, value: new_value, pos1: _, pos2: _ } => {
             // Simply pass its value
             *value = new_value;
         }
+        ASTNode::Chars{ ref bytes, pos1: _, pos2: _ } => {
+            // Glue the bytes together in the chosen byte order (the tokenizer made sure they fit)
+            *value = 0;
+            if settings.little_endian {
+                for b in bytes.iter().rev() { *value = (*value << 8) | *b as u128; }
+            } else {
+                for b in bytes.iter() { *value = (*value << 8) | *b as u128; }
+            }
+        }
+
+        // Ignore the rest
+        _ => {}
+    }
+
+    // Done
+    return node;
+}
+
+/// Traverses the given node as a range and computes its (half-open) bounds.
+/// 
+/// **Arguments**
+///  * `node`: The node to traverse, which must have been typed as a range.
+///  * `start_value`: The start of the range on which we operate.
+///  * `end_value`: The (exclusive) end of the range on which we operate.
+///  * `symtable`: The symbol table that we use to keep track of identifiers.
+///  * `settings`: The Settings that determine the default width and what to do on overflow.
+///  * `file`: The opened file that functions read from, if any.
+///  * `error`: Can be set to indicate an error has occurred.
+/// 
+/// **Returns**  
+/// The given node, or else a replacement if deemed necessary.
+fn traverse_range(mut node: ASTNode, start_value: &mut u128, end_value: &mut u128, symtable: &mut SymbolTable, settings: &Settings, file: Option<&BinaryFile>, error: &mut bool) -> ASTNode {
+    // Switch on the node
+    match node {
+        ASTNode::Expr{ override_kind: _, kind, signed, width, range: _, ref mut expr, pos1, pos2: _ } => {
+            // Traverse to resolve the child's bounds
+            let mut child_start: u128 = 0; let mut child_end: u128 = 0;
+            **expr = traverse_range(*expr.clone(), &mut child_start, &mut child_end, symtable, settings, file, error);
+
+            // Make sure both bounds fit in the width of the expression
+            let width = width.or(settings.width);
+            match (convert(child_start, kind, width, signed, settings), convert(child_end, kind, width, signed, settings)) {
+                (Some(start), Some(end)) => {
+                    *start_value = start;
+                    *end_value   = end;
+                }
+                _ => {
+                    // Overflow
+                    eprintln!("   {}: Overflow occurred while fitting {}..{} in {}.", pos1, as_string(child_start, signed), as_string(child_end, signed), width);
+                    *error = true;
+                }
+            }
+        }
+
+        ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref identifier, ref mut expr, pos1: _, pos2: _ } => {
+            // Traverse to resolve the child's bounds (the type traversal made sure this isn't a compound assignment)
+            **expr = traverse_range(*expr.clone(), start_value, end_value, symtable, settings, file, error);
+
+            // Also push the update to the symbol table
+            let entry = symtable.get_mut(identifier).unwrap();
+            entry.value = *start_value;
+            entry.end   = Some(*end_value);
+        }
+        ASTNode::Range{ override_kind: _, kind: _, signed, width, by_length, ref mut start, ref mut end, pos1, pos2: _ } => {
+            // Traverse to resolve the bounds' values
+            let mut start_val: u128 = 0; let mut end_val: u128 = 0;
+            **start = traverse_node(*start.clone(), &mut start_val, symtable, settings, file, error);
+            **end   = traverse_node(*end.clone(), &mut end_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
+
+            // If the range is given by its length, compute where it ends
+            if by_length {
+                let (result, overflow) = overflowing_op!(start_val, end_val, signed, overflowing_add);
+                match fit(result, overflow, width, signed, settings) {
+                    Some(val) => {
+                        end_val = val;
+                    }
+                    None => {
+                        // Overflow
+                        eprintln!("   {}: Overflow occurred while performing {}..+{}.", pos1, as_string(start_val, signed), as_string(end_val, signed));
+                        *error = true;
+                        return node;
+                    }
+                }
+            }
+
+            // A range may be empty, but it cannot end before it starts
+            if (signed && (end_val as i128) < (start_val as i128)) || (!signed && end_val < start_val) {
+                eprintln!("   {}: Range end {} lies before its start {}.", pos1, as_string(end_val, signed), as_string(start_val, signed));
+                *error = true;
+                return node;
+            }
+            *start_value = start_val;
+            *end_value   = end_val;
+        }
+        ASTNode::Ternary{ override_kind: _, kind, signed, width, ref mut cond, ref mut if_true, ref mut if_false, pos1, pos2: _ } => {
+            // Traverse to resolve the condition's value
+            let mut cond_val: u128 = 0;
+            **cond = traverse_node(*cond.clone(), &mut cond_val, symtable, settings, file, error);
+            let width = width.or(settings.width);
+
+            // Only resolve the branch that is taken, so the other one can't cause errors (or assignments)
+            let mut branch_start: u128 = 0; let mut branch_end: u128 = 0;
+            if cond_val != 0 {
+                **if_true = traverse_range(*if_true.clone(), &mut branch_start, &mut branch_end, symtable, settings, file, error);
+            } else {
+                **if_false = traverse_range(*if_false.clone(), &mut branch_start, &mut branch_end, symtable, settings, file, error);
+            }
+
+            // Make sure both bounds fit in the width of the conditional
+            match (convert(branch_start, kind, width, signed, settings), convert(branch_end, kind, width, signed, settings)) {
+                (Some(start), Some(end)) => {
+                    *start_value = start;
+                    *end_value   = end;
+                }
+                _ => {
+                    // Overflow
+                    eprintln!("   {}: Overflow occurred while fitting {}..{} in {}.", pos1, as_string(branch_start, signed), as_string(branch_end, signed), width);
+                    *error = true;
+                }
+            }
+        }
+        ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, ref identifier, ref mut args, pos1, pos2: _ } => {
+            // Traverse to resolve the arguments' bounds (only range functions can result in a range)
+            let mut arg_ranges: Vec<Range> = Vec::with_capacity(args.len());
+            for arg in args.iter_mut() {
+                let mut arg_start: u128 = 0; let mut arg_end: u128 = 0;
+                *arg = traverse_range(arg.clone(), &mut arg_start, &mut arg_end, symtable, settings, file, error);
+                arg_ranges.push((arg_start, arg_end));
+            }
+
+            // Call the function (which must exist, as the symbol table traversal checked that)
+            let args_signed = args.iter().any(get_signed);
+            let func = match builtins::get(identifier).unwrap().func {
+                BuiltinFunc::RangeToRange(func) => func,
+                _ => { panic!("Encountered non-range function '{}' in a range at pos {}: this should never happen!", identifier, pos1); }
+            };
+            match func(&arg_ranges, args_signed) {
+                Ok((start, end)) => {
+                    *start_value = start;
+                    *end_value   = end;
+                }
+                Err(reason) => {
+                    eprintln!("   {}: {} while performing {}.", pos1, reason, call_string(identifier, &[], &arg_ranges, args_signed));
+                    *error = true;
+                }
+            }
+        }
+        ASTNode::MonOp{ kind: _, signed: _, width: _, ref mut expr, pos1: _, pos2: _ } => {
+            // Traverse to resolve the child's bounds
+            **expr = traverse_range(*expr.clone(), start_value, end_value, symtable, settings, file, error);
+        }
+
+        ASTNode::Id{ ref identifier, pos1: _, pos2: _ } => {
+            // Fetch the bounds from the symbol table (the type traversal made sure it is a range)
+            let var = symtable.get(identifier).unwrap();
+            *start_value = var.value;
+            *end_value   = var.end.unwrap();
+        }
 
         // Ignore the rest
         _ => {}
@@ -158,15 +865,26 @@ fn traverse_node(mut node: ASTNode, value: &mut u64, symtable: &mut SymbolTable,
 /// 
 /// **Arguments**
 ///  * `ast`: The AST to traverse.
-///  * `value`: A reference to the value which will contain the result.
+///  * `value`: A reference to the value which will contain the result (or the start of the range, if the result is one).
+///  * `end`: A reference to the (exclusive) end of the range that will be set if the result is one, or None otherwise.
 ///  * `symbol_table`: The symbol table that we use to keep track of identifiers.
+///  * `settings`: The Settings that determine the default width and what to do on overflow.
+///  * `file`: The opened file that functions read from, if any.
 /// 
 /// **Returns**  
 /// The node to traverse, or else a replacement if the algorithm deems it necessary.
-pub fn traverse(ast: ASTNode, value: &mut u64, symbol_table: &mut SymbolTable) -> Option<ASTNode> {
-    // Simply return the traverse_node call
+pub fn traverse(ast: ASTNode, value: &mut u128, end: &mut Option<u128>, symbol_table: &mut SymbolTable, settings: &Settings, file: Option<&BinaryFile>) -> Option<ASTNode> {
+    // Traverse as a range or as a value, depending on what the type traversal decided
     let mut error: bool = false;
-    let new_ast = traverse_node(ast, value, symbol_table, &mut error);
+    let new_ast = if get_range(&ast) {
+        let mut end_value: u128 = 0;
+        let new_ast = traverse_range(ast, value, &mut end_value, symbol_table, settings, file, &mut error);
+        *end = Some(end_value);
+        new_ast
+    } else {
+        *end = None;
+        traverse_node(ast, value, symbol_table, settings, file, &mut error)
+    };
     if error { return None; }
     return Some(new_ast);
 }
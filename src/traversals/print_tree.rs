@@ -65,6 +65,25 @@ fn traverse_node(mut node: ASTNode, indent: usize, symtable: &SymbolTable) -> AS
         ASTNode::ShowVars{ pos1: _, pos2: _ } => {
             println!("{}ShowVars", n_spaces!(indent));
         }
+        ASTNode::Set{ ref setting, ref value, pos1: _, pos2: _ } => {
+            println!("{}Set({} = {})", n_spaces!(indent), setting, value);
+        }
+        ASTNode::Open{ ref path, pos1: _, pos2: _ } => {
+            println!("{}Open({})", n_spaces!(indent), path);
+        }
+        ASTNode::LoadElf{ ref path, pos1: _, pos2: _ } => {
+            println!("{}LoadElf({})", n_spaces!(indent), path);
+        }
+        ASTNode::LoadPe{ ref path, pos1: _, pos2: _ } => {
+            println!("{}LoadPe({})", n_spaces!(indent), path);
+        }
+        ASTNode::Dump{ ref mut expr, ref mut len, pos1: _, pos2: _ } => {
+            // Print the dump with its offset and length
+            println!("{}Dump(", n_spaces!(indent));
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            if let Some(len) = len { **len = traverse_node(*len.clone(), indent + 3, symtable); }
+            println!("{})", n_spaces!(indent));
+        }
         ASTNode::ClearHist{ pos1: _, pos2: _ } => {
             println!("{}ClearHist", n_spaces!(indent));
         }
@@ -75,9 +94,51 @@ fn traverse_node(mut node: ASTNode, indent: usize, symtable: &SymbolTable) -> AS
             println!("{}Exit", n_spaces!(indent));
         }
 
-        ASTNode::Expr{ override_kind, kind, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Expr{ override_kind, kind, signed, width, range, ref mut expr, pos1: _, pos2: _ } => {
             // Print the child of the expression recursively
-            println!("{}Expr<{} {:?}>(", n_spaces!(indent), override_kind, kind);
+            println!("{}Expr<{} {:?} {} {}{}>(", n_spaces!(indent), override_kind, kind, signed, width, if range { " range" } else { "" });
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::CmpExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the comparison-expression recursively
+            println!("{}CmpExpr<{:?}>(", n_spaces!(indent), kind);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::RangeExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the range-expression recursively
+            println!("{}RangeExpr<{:?}>(", n_spaces!(indent), kind);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::BitOrExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the bitwise or-expression recursively
+            println!("{}BitOrExpr<{:?}>(", n_spaces!(indent), kind);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::BitXorExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the bitwise xor-expression recursively
+            println!("{}BitXorExpr<{:?}>(", n_spaces!(indent), kind);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::BitAndExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the bitwise and-expression recursively
+            println!("{}BitAndExpr<{:?}>(", n_spaces!(indent), kind);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::ShiftExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the shift-expression recursively
+            println!("{}ShiftExpr<{:?}>(", n_spaces!(indent), kind);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::SumExpr{ kind, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the child of the sum recursively
+            println!("{}SumExpr<{:?}>(", n_spaces!(indent), kind);
             **expr = traverse_node(*expr.clone(), indent + 3, symtable);
             println!("{})", n_spaces!(indent));
         }
@@ -106,32 +167,108 @@ fn traverse_node(mut node: ASTNode, indent: usize, symtable: &SymbolTable) -> AS
             println!("{})", n_spaces!(indent));
         }
 
-        ASTNode::Assign{ override_kind, kind, ref identifier, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Assign{ override_kind, kind, signed, width, operator, ref identifier, ref mut expr, pos1: _, pos2: _ } => {
             // Print the binop with its expressions - but now we use indent
-            println!("{}Assign<{} {:?}>(", n_spaces!(indent), override_kind, kind);
-            println!("{}{} =", n_spaces!(indent + 3), identifier);
+            println!("{}Assign<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            println!("{}{} {:?}", n_spaces!(indent + 3), identifier, operator);
             **expr = traverse_node(*expr.clone(), indent + 3, symtable);
             println!("{})", n_spaces!(indent));
         }
-        ASTNode::BinOpLow{ override_kind, kind, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+        ASTNode::BinOpLow{ override_kind, kind, signed, width, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
             // Print the binop with its expressions - but now we use indent
-            println!("{}BinOpL<{} {:?}>(", n_spaces!(indent), override_kind, kind);
+            println!("{}BinOpL<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
             **left = traverse_node(*left.clone(), indent + 3, symtable);
             println!("{}{:?}", n_spaces!(indent + 3), operator);
             **right = traverse_node(*right.clone(), indent + 3, symtable);
             println!("{})", n_spaces!(indent));
         }
-        ASTNode::BinOpHigh{ override_kind, kind, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+        ASTNode::BinOpHigh{ override_kind, kind, signed, width, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
             // Print the binop with its expressions - but now we use indent
-            println!("{}BinOpH<{} {:?}>(", n_spaces!(indent), override_kind, kind);
+            println!("{}BinOpH<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
             **left = traverse_node(*left.clone(), indent + 3, symtable);
             println!("{}{:?}", n_spaces!(indent + 3), operator);
             **right = traverse_node(*right.clone(), indent + 3, symtable);
             println!("{})", n_spaces!(indent));
         }
-        ASTNode::MonOp{ kind, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::BinOpShift{ override_kind, kind, signed, width, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
             // Print the binop with its expressions - but now we use indent
-            println!("{}MonOp<{:?}>(", n_spaces!(indent), kind);
+            println!("{}BinOpS<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            **left = traverse_node(*left.clone(), indent + 3, symtable);
+            println!("{}{:?}", n_spaces!(indent + 3), operator);
+            **right = traverse_node(*right.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::BinOpBitwise{ override_kind, kind, signed, width, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+            // Print the binop with its expressions - but now we use indent
+            println!("{}BinOpB<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            **left = traverse_node(*left.clone(), indent + 3, symtable);
+            println!("{}{:?}", n_spaces!(indent + 3), operator);
+            **right = traverse_node(*right.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::BinOpCmp{ override_kind, kind, signed, width, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+            // Print the binop with its expressions - but now we use indent
+            println!("{}BinOpC<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            **left = traverse_node(*left.clone(), indent + 3, symtable);
+            println!("{}{:?}", n_spaces!(indent + 3), operator);
+            **right = traverse_node(*right.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::Range{ override_kind, kind, signed, width, by_length, ref mut start, ref mut end, pos1: _, pos2: _ } => {
+            // Print the range with its bounds - but now we use indent
+            println!("{}Range<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            **start = traverse_node(*start.clone(), indent + 3, symtable);
+            println!("{}{}", n_spaces!(indent + 3), if by_length { "..+" } else { ".." });
+            **end = traverse_node(*end.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::Ternary{ override_kind, kind, signed, width, ref mut cond, ref mut if_true, ref mut if_false, pos1: _, pos2: _ } => {
+            // Print the conditional with its expressions - but now we use indent
+            println!("{}Ternary<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            **cond = traverse_node(*cond.clone(), indent + 3, symtable);
+            println!("{}?", n_spaces!(indent + 3));
+            **if_true = traverse_node(*if_true.clone(), indent + 3, symtable);
+            println!("{}:", n_spaces!(indent + 3));
+            **if_false = traverse_node(*if_false.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::UnaryOp{ override_kind, kind, signed, width, operator, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the unary op with its expression - but now we use indent
+            println!("{}UnaryOp<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            println!("{}{:?}", n_spaces!(indent + 3), operator);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::Cast{ override_kind, kind, signed, width, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the cast with its expression - but now we use indent
+            println!("{}Cast<{} {:?} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::Slice{ override_kind, kind, signed, width, bits, ref mut expr, ref mut hi, ref mut lo, pos1: _, pos2: _ } => {
+            // Print the slice with its value and bits - but now we use indent
+            println!("{}Slice<{} {:?} {} {} {}>(", n_spaces!(indent), override_kind, kind, signed, width, bits);
+            **expr = traverse_node(*expr.clone(), indent + 3, symtable);
+            println!("{}[", n_spaces!(indent + 3));
+            **hi = traverse_node(*hi.clone(), indent + 3, symtable);
+            if let Some(lo) = lo {
+                println!("{}:", n_spaces!(indent + 3));
+                **lo = traverse_node(*lo.clone(), indent + 3, symtable);
+            }
+            println!("{}]", n_spaces!(indent + 3));
+            println!("{})", n_spaces!(indent));
+        }
+        ASTNode::Call{ override_kind, kind, signed, width, ref identifier, ref mut args, pos1: _, pos2: _ } => {
+            // Print the call with its arguments - but now we use indent
+            println!("{}Call<{} {:?} {} {}>({}(", n_spaces!(indent), override_kind, kind, signed, width, identifier);
+            for arg in args.iter_mut() {
+                *arg = traverse_node(arg.clone(), indent + 3, symtable);
+            }
+            println!("{}))", n_spaces!(indent));
+        }
+        ASTNode::MonOp{ kind, signed, width, ref mut expr, pos1: _, pos2: _ } => {
+            // Print the binop with its expressions - but now we use indent
+            println!("{}MonOp<{:?} {} {}>(", n_spaces!(indent), kind, signed, width);
             **expr = traverse_node(*expr.clone(), indent + 3, symtable);
             println!("{})", n_spaces!(indent));
         }
@@ -139,10 +276,10 @@ fn traverse_node(mut node: ASTNode, indent: usize, symtable: &SymbolTable) -> AS
         ASTNode::Id{ ref identifier, pos1: _, pos2: _ } => {
             // Try to get the kind and the value
             let mut kind      = ValueKind::Undefined;
-            let mut value:u64 = 0;
+            let mut value:u128 = 0;
             if symtable.contains_key(identifier) {
-                kind  = symtable.get(identifier).unwrap().0;
-                value = symtable.get(identifier).unwrap().1;
+                kind  = symtable.get(identifier).unwrap().kind;
+                value = symtable.get(identifier).unwrap().value;
             }
 
             // Print it
@@ -151,6 +288,12 @@ fn traverse_node(mut node: ASTNode, indent: usize, symtable: &SymbolTable) -> AS
         ASTNode::Const{ kind, value, pos1: _, pos2: _ } => {
             println!("{}{}<{:?}>", n_spaces!(indent), value, kind);
         }
+        ASTNode::Chars{ ref bytes, pos1: _, pos2: _ } => {
+            println!("{}Chars({:?})", n_spaces!(indent), bytes);
+        }
+        ASTNode::Float{ value, pos1: _, pos2: _ } => {
+            println!("{}Float({:?})", n_spaces!(indent), value);
+        }
 
     }
 
@@ -13,7 +13,9 @@
 **/
 
 use crate::ast::symbol_table::SymbolTable;
-use crate::ast::parser::ValueKind;
+use crate::ast::symbol_table::Variable;
+use crate::ast::builtins;
+use crate::ast::parser::AssignOperator;
 use crate::ast::parser::ASTNode;
 
 
@@ -30,31 +32,74 @@ use crate::ast::parser::ASTNode;
 fn traverse_node(mut node: ASTNode, symbol_table: &mut SymbolTable, error: &mut bool) -> ASTNode {
     // Switch on the node
     match node {
-        ASTNode::Expr{ override_kind: _, kind: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, ref mut expr, pos1: _, pos2: _ } => {
             // Traverse to resolve it
             **expr = traverse_node(*expr.clone(), symbol_table, error);
         }
 
-        ASTNode::Assign{ override_kind: _, kind: _, ref identifier, ref mut expr, pos1: _, pos2: _ } => {
-            // Try to make a note in the table
+        ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator, ref identifier, ref mut expr, pos1, pos2: _ } => {
+            // Try to make a note in the table, unless it's a compound assignment (which needs an existing variable)
             if !symbol_table.contains_key(identifier) {
-                symbol_table.insert(identifier.clone(), (ValueKind::Undefined, 0));
+                if operator == AssignOperator::Assign {
+                    symbol_table.insert(identifier.clone(), Variable::undefined());
+                } else {
+                    eprintln!("   {}: Unknown identifier '{}'.", pos1, identifier);
+                    *error = true;
+                }
             }
 
             // Now traverse into its child
             **expr = traverse_node(*expr.clone(), symbol_table, error);
         }
-        ASTNode::BinOpLow{ override_kind: _, kind: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
-        ASTNode::BinOpHigh{ override_kind: _, kind: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+        ASTNode::BinOpLow{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpHigh{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpShift{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpCmp{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::Range{ override_kind: _, kind: _, signed: _, width: _, by_length: _, start: ref mut left, end: ref mut right, pos1: _, pos2: _ } => {
             // Traverse both children return
             **left = traverse_node(*left.clone(), symbol_table, error);
             **right = traverse_node(*right.clone(), symbol_table, error);
         }
+        ASTNode::Ternary{ override_kind: _, kind: _, signed: _, width: _, ref mut cond, ref mut if_true, ref mut if_false, pos1: _, pos2: _ } => {
+            // Traverse all three children
+            **cond = traverse_node(*cond.clone(), symbol_table, error);
+            **if_true = traverse_node(*if_true.clone(), symbol_table, error);
+            **if_false = traverse_node(*if_false.clone(), symbol_table, error);
+        }
 
-        ASTNode::MonOp{ kind: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::UnaryOp{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut expr, pos1: _, pos2: _ } |
+        ASTNode::Cast{ override_kind: _, kind: _, signed: _, width: _, ref mut expr, pos1: _, pos2: _ } |
+        ASTNode::MonOp{ kind: _, signed: _, width: _, ref mut expr, pos1: _, pos2: _ } => {
             // Traverse its child return
             **expr = traverse_node(*expr.clone(), symbol_table, error);
         }
+        ASTNode::Slice{ override_kind: _, kind: _, signed: _, width: _, bits: _, ref mut expr, ref mut hi, ref mut lo, pos1: _, pos2: _ } => {
+            // Traverse the value and its bits
+            **expr = traverse_node(*expr.clone(), symbol_table, error);
+            **hi = traverse_node(*hi.clone(), symbol_table, error);
+            if let Some(lo) = lo { **lo = traverse_node(*lo.clone(), symbol_table, error); }
+        }
+        ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, ref identifier, ref mut args, pos1, pos2: _ } => {
+            // See if the function exists and is called with the proper number of arguments
+            match builtins::get(identifier) {
+                Some(builtin) => {
+                    if args.len() != builtin.args.len() {
+                        eprintln!("   {}: Function '{}' expects {} argument{}, but got {}.", pos1, identifier, builtin.args.len(), if builtin.args.len() == 1 { "" } else { "s" }, args.len());
+                        *error = true;
+                    }
+                }
+                None => {
+                    eprintln!("   {}: Unknown function '{}'.", pos1, identifier);
+                    *error = true;
+                }
+            }
+
+            // Traverse the arguments too
+            for arg in args.iter_mut() {
+                *arg = traverse_node(arg.clone(), symbol_table, error);
+            }
+        }
 
         ASTNode::Id { ref identifier, pos1, pos2: _ } => {
             // See if we have seen it
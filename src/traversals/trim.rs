@@ -14,6 +14,7 @@
 **/
 
 use crate::ast::parser::ASTNode;
+use crate::ast::parser::ValueWidth;
 
 
 /***** NODE FUNCTIONS *****/
@@ -27,13 +28,13 @@ use crate::ast::parser::ASTNode;
 fn traverse_node(mut node: ASTNode) -> ASTNode {
     // Switch on the node
     match node {
-        ASTNode::Expr{ override_kind: _, kind: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, ref mut expr, pos1: _, pos2: _ } => {
             // Traverse to resolve it
             **expr = traverse_node(*expr.clone());
 
             // If that turns out to be an expression as well, return our child instead of that
             match **expr {
-                ASTNode::Expr{ override_kind: _, kind: _, expr: _, pos1: _, pos2: _ } => {
+                ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {
                     // Return the child instead
                     return *expr.clone();
                 }
@@ -45,6 +46,13 @@ fn traverse_node(mut node: ASTNode) -> ASTNode {
             }
         }
 
+        ASTNode::CmpExpr{ kind, ref mut expr, pos1, pos2 } |
+        ASTNode::RangeExpr{ kind, ref mut expr, pos1, pos2 } |
+        ASTNode::BitOrExpr{ kind, ref mut expr, pos1, pos2 } |
+        ASTNode::BitXorExpr{ kind, ref mut expr, pos1, pos2 } |
+        ASTNode::BitAndExpr{ kind, ref mut expr, pos1, pos2 } |
+        ASTNode::ShiftExpr{ kind, ref mut expr, pos1, pos2 } |
+        ASTNode::SumExpr{ kind, ref mut expr, pos1, pos2 } |
         ASTNode::Term{ kind, ref mut expr, pos1, pos2 } |
         ASTNode::Factor{ kind, ref mut expr, pos1, pos2 } |
         ASTNode::SmallFactor{ kind, ref mut expr, pos1, pos2 } |
@@ -54,7 +62,7 @@ fn traverse_node(mut node: ASTNode) -> ASTNode {
 
             // If the child is an expression, remove this; otherwise, replace with an expression ourselves
             match **expr {
-                ASTNode::Expr{ override_kind: _, kind: _, expr: _, pos1: _, pos2: _ } => {
+                ASTNode::Expr{ override_kind: _, kind: _, signed: _, width: _, range: _, expr: _, pos1: _, pos2: _ } => {
                     // Return the child instead
                     return *expr.clone();
                 }
@@ -64,6 +72,9 @@ fn traverse_node(mut node: ASTNode) -> ASTNode {
                     return ASTNode::Expr{
                         override_kind: false,
                         kind: kind,
+                        signed: false,
+                        width: ValueWidth::Undefined,
+                        range: false,
                         expr: expr.clone(),
                         pos1: pos1, pos2: pos2
                     };
@@ -71,24 +82,51 @@ fn traverse_node(mut node: ASTNode) -> ASTNode {
             }
         }
 
-        ASTNode::Assign{ override_kind: _, kind: _, identifier: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator: _, identifier: _, ref mut expr, pos1: _, pos2: _ } => {
             // Traverse its child return
             **expr = traverse_node(*expr.clone());
             return node;
         }
-        ASTNode::BinOpLow{ override_kind: _, kind: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
-        ASTNode::BinOpHigh{ override_kind: _, kind: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+        ASTNode::BinOpLow{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpHigh{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpShift{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::BinOpCmp{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut left, ref mut right, pos1: _, pos2: _ } |
+        ASTNode::Range{ override_kind: _, kind: _, signed: _, width: _, by_length: _, start: ref mut left, end: ref mut right, pos1: _, pos2: _ } => {
             // Traverse both children return
             **left = traverse_node(*left.clone());
             **right = traverse_node(*right.clone());
             return node;
         }
+        ASTNode::Ternary{ override_kind: _, kind: _, signed: _, width: _, ref mut cond, ref mut if_true, ref mut if_false, pos1: _, pos2: _ } => {
+            // Traverse all three children return
+            **cond = traverse_node(*cond.clone());
+            **if_true = traverse_node(*if_true.clone());
+            **if_false = traverse_node(*if_false.clone());
+            return node;
+        }
 
-        ASTNode::MonOp{ kind: _, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::UnaryOp{ override_kind: _, kind: _, signed: _, width: _, operator: _, ref mut expr, pos1: _, pos2: _ } |
+        ASTNode::Cast{ override_kind: _, kind: _, signed: _, width: _, ref mut expr, pos1: _, pos2: _ } |
+        ASTNode::MonOp{ kind: _, signed: _, width: _, ref mut expr, pos1: _, pos2: _ } => {
             // Traverse its child return
             **expr = traverse_node(*expr.clone());
             return node;
         }
+        ASTNode::Slice{ override_kind: _, kind: _, signed: _, width: _, bits: _, ref mut expr, ref mut hi, ref mut lo, pos1: _, pos2: _ } => {
+            // Traverse the value and its bits return
+            **expr = traverse_node(*expr.clone());
+            **hi = traverse_node(*hi.clone());
+            if let Some(lo) = lo { **lo = traverse_node(*lo.clone()); }
+            return node;
+        }
+        ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, identifier: _, ref mut args, pos1: _, pos2: _ } => {
+            // Traverse all of its arguments
+            for arg in args.iter_mut() {
+                *arg = traverse_node(arg.clone());
+            }
+            return node;
+        }
 
         _ => {
             // Just return the node itself
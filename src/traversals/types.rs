@@ -12,37 +12,61 @@
  *   Traverses the AST to properly annotate everything of the ConstKind.
 **/
 
+use crate::settings::Settings;
 use crate::ast::symbol_table::SymbolTable;
+use crate::ast::symbol_table::Variable;
 use crate::ast::parser::ValueKind;
+use crate::ast::parser::ValueWidth;
+use crate::ast::parser::AssignOperator;
+use crate::ast::parser::ComparisonOperator;
 use crate::ast::parser::ASTNode;
+use crate::ast::parser::get_range;
+use crate::ast::parser::get_float;
+use crate::ast::parser::get_const;
+use crate::ast::symbols::Symbol;
+use crate::ast::builtins;
+use crate::ast::builtins::BuiltinFunc;
 
 
 /***** HELPER MACROS *****/
-/// Returns the child's override and kind, based on its type.
+/// Returns the child's override, kind, signedness and width, based on its type.
 /// 
 /// **Arguments**
 ///  * `child`: The node to extract the type data from.
+///  * `symtable`: The symbol table that we use to keep track of identifiers.
+///  * `in_signed`: Whether constants and identifiers are interpreted as signed values in this part of the tree.
+///  * `in_width`: The width that constants get in this part of the tree.
+///  * `error`: Can be set to indicate an error has occurred.
 /// 
 /// **Returns**  
-/// The override_kind and kind of that child as a tuple (in that order). If something's wrong and the type doesn't have a type, panics.
+/// The override_kind, kind, signed and width of that child as a tuple (in that order). If something's wrong and the type doesn't have a type, panics.
 macro_rules! get_child_kind {
-    ($child: expr, $symtable: expr, $error: expr) => {
+    ($child: expr, $symtable: expr, $in_signed: expr, $in_width: expr, $error: expr) => {
         match $child {
-            ASTNode::Expr{ override_kind: child_override_kind, kind: child_kind, expr: _, pos1: _, pos2: _ } |
-            ASTNode::Assign{ override_kind: child_override_kind, kind: child_kind, identifier: _, expr: _, pos1: _, pos2: _ } |
-            ASTNode::BinOpLow{ override_kind: child_override_kind, kind: child_kind, operator: _, left: _, right: _, pos1: _, pos2: _ } |
-            ASTNode::BinOpHigh{ override_kind: child_override_kind, kind: child_kind, operator: _, left: _, right: _, pos1: _, pos2: _ } => {
-                // Take on the override kind and the kind from this child
-                (child_override_kind, child_kind)
-            }
-            ASTNode::MonOp{ kind: child_kind, expr: _, pos1: _, pos2: _ } => {
+            ASTNode::Expr{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, range: _, expr: _, pos1: _, pos2: _ } |
+            ASTNode::Assign{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, identifier: _, expr: _, pos1: _, pos2: _ } |
+            ASTNode::BinOpLow{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, left: _, right: _, pos1: _, pos2: _ } |
+            ASTNode::BinOpHigh{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, left: _, right: _, pos1: _, pos2: _ } |
+            ASTNode::BinOpShift{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, left: _, right: _, pos1: _, pos2: _ } |
+            ASTNode::BinOpBitwise{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, left: _, right: _, pos1: _, pos2: _ } |
+            ASTNode::BinOpCmp{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, left: _, right: _, pos1: _, pos2: _ } |
+            ASTNode::Range{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, by_length: _, start: _, end: _, pos1: _, pos2: _ } |
+            ASTNode::Ternary{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, cond: _, if_true: _, if_false: _, pos1: _, pos2: _ } |
+            ASTNode::UnaryOp{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, operator: _, expr: _, pos1: _, pos2: _ } |
+            ASTNode::Cast{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, expr: _, pos1: _, pos2: _ } |
+            ASTNode::Slice{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, bits: _, expr: _, hi: _, lo: _, pos1: _, pos2: _ } |
+            ASTNode::Call{ override_kind: child_override_kind, kind: child_kind, signed: child_signed, width: child_width, identifier: _, args: _, pos1: _, pos2: _ } => {
+                // Take on the override kind, the kind, the signedness and the width from this child
+                (child_override_kind, child_kind, child_signed, child_width)
+            }
+            ASTNode::MonOp{ kind: child_kind, signed: child_signed, width: child_width, expr: _, pos1: _, pos2: _ } => {
                 // Set the child's type to ours, with an always-override type
-                (true, child_kind)
+                (true, child_kind, child_signed, child_width)
             }
 
             ASTNode::Id{ ref identifier, pos1, pos2: _ } => {
                 // Get the data
-                let (kind, _) = $symtable.get(identifier).unwrap();
+                let Variable{ kind, value: _, signed, width, end: _ } = $symtable.get(identifier).unwrap();
 
                 // If the type is undefined, it's never been initialized (ans)
                 if *kind == ValueKind::Undefined {
@@ -51,11 +75,19 @@ macro_rules! get_child_kind {
                 }
 
                 // Return the valuekind in its stead
-                (false, *kind)
+                (false, *kind, *signed || $in_signed, *width)
             }
             ASTNode::Const{ kind: child_kind, value: _, pos1: _, pos2: _ } => {
-                // Set the child's type to ours, with a never overriding type
-                (false, child_kind)
+                // Set the child's type to ours, with a never overriding type and the width of the context
+                (false, child_kind, $in_signed, $in_width)
+            }
+            ASTNode::Chars{ bytes: _, pos1: _, pos2: _ } => {
+                // Character literals are shown as hexadecimal by default, but are otherwise just like constants
+                (false, ValueKind::Hexadecimal, $in_signed, $in_width)
+            }
+            ASTNode::Float{ value: _, pos1: _, pos2: _ } => {
+                // Misplaced float constants have already been reported, so just pretend they're decimal
+                (false, ValueKind::Decimal, $in_signed, $in_width)
             }
 
             _ => {
@@ -66,6 +98,48 @@ macro_rules! get_child_kind {
     }
 }
 
+/// Checks whether the given (already typed) child expression is a range or a value, and throws an error if it's not what we expect.
+/// 
+/// **Arguments**
+///  * `child`: The child expression to check.
+///  * `range`: Whether we expect a range (true) or a value (false).
+///  * `error`: Can be set to indicate an error has occurred.
+macro_rules! expect_range {
+    ($child: expr, $range: expr, $error: expr) => {
+        if get_range(&$child) != $range {
+            eprintln!("   {}: Expected a {}, got a {}.", $child.pos().0, if $range { "range" } else { "value" }, if $range { "value" } else { "range" });
+            *$error = true;
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Returns whether the given node (which is the direct child of an expression) results in a range.
+/// 
+/// **Arguments**
+///  * `child`: The node to check.
+///  * `symtable`: The symbol table that we use to keep track of identifiers.
+/// 
+/// **Returns**  
+/// True if the node results in a range, or false if it results in a value.
+fn is_range(child: &ASTNode, symtable: &SymbolTable) -> bool {
+    match child {
+        ASTNode::Range{ override_kind: _, kind: _, signed: _, width: _, by_length: _, start: _, end: _, pos1: _, pos2: _ } => { true }
+
+        ASTNode::Assign{ override_kind: _, kind: _, signed: _, width: _, operator: _, identifier: _, expr, pos1: _, pos2: _ } |
+        ASTNode::MonOp{ kind: _, signed: _, width: _, expr, pos1: _, pos2: _ }                                                => { get_range(expr) }
+        ASTNode::Ternary{ override_kind: _, kind: _, signed: _, width: _, cond: _, if_true, if_false: _, pos1: _, pos2: _ } => { get_range(if_true) }
+        ASTNode::Call{ override_kind: _, kind: _, signed: _, width: _, identifier, args: _, pos1: _, pos2: _ }               => { builtins::get(identifier).unwrap().returns_range() }
+        ASTNode::Id{ identifier, pos1: _, pos2: _ }                                                                          => { symtable.get(identifier).unwrap().end.is_some() }
+
+        _ => { false }
+    }
+}
+
 
 
 
@@ -76,44 +150,91 @@ macro_rules! get_child_kind {
 /// **Arguments**
 ///  * `node`: The node to traverse.
 ///  * `symtable`: The symbol table that we use to keep track of identifiers.
+///  * `settings`: The Settings that determine the width of new variables.
+///  * `in_signed`: Whether constants and identifiers are interpreted as signed values in this part of the tree.
+///  * `in_width`: The width that constants get in this part of the tree. If Undefined, they take on the width of whatever they're combined with.
 ///  * `error`: Can be set to indicate an error has occurred.
 /// 
 /// **Returns**  
 /// The given node, or else a replacement if deemed necessary.
-fn traverse_node(mut node: ASTNode, symtable: &mut SymbolTable, error: &mut bool) -> ASTNode {
+fn traverse_node(mut node: ASTNode, symtable: &mut SymbolTable, settings: &Settings, in_signed: bool, in_width: ValueWidth, error: &mut bool) -> ASTNode {
+    // Booleans only survive the bitwise operators, so already note whether we are one
+    let bitwise = if let ASTNode::BinOpBitwise{ override_kind: _, kind: _, signed: _, width: _, operator: _, left: _, right: _, pos1: _, pos2: _ } = node { true } else { false };
+
     // Switch on the node
     match node {
-        ASTNode::Expr{ ref mut override_kind, ref mut kind, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Expr{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, ref mut range, ref mut expr, pos1: _, pos2: _ } => {
             // Traverse to resolve the child's type
-            **expr = traverse_node(*expr.clone(), symtable, error);
+            **expr = traverse_node(*expr.clone(), symtable, settings, in_signed, in_width, error);
 
             // Based on the child, take what we need to properly propogate the type
-            let (child_override_kind, child_kind) = get_child_kind!(**expr, symtable, error);
+            let (child_override_kind, child_kind, child_signed, child_width) = get_child_kind!(**expr, symtable, in_signed, in_width, error);
             *override_kind = child_override_kind;
             *kind = child_kind;
+            *signed = child_signed;
+            *width = child_width;
+            *range = is_range(expr, symtable);
         }
 
-        ASTNode::Assign{ ref mut override_kind, ref mut kind, ref identifier, ref mut expr, pos1: _, pos2: _ } => {
+        ASTNode::Assign{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator, ref identifier, ref mut expr, pos1, pos2: _ } => {
             // Traverse through the child to resolve
-            **expr  = traverse_node(*expr.clone(), symtable, error);
+            **expr  = traverse_node(*expr.clone(), symtable, settings, in_signed, in_width, error);
 
-            // Based on the child, take what we need to properly propogate the type
-            let (child_override_kind, child_kind) = get_child_kind!(**expr, symtable, error);
-            *override_kind = child_override_kind;
-            *kind = child_kind;
+            // Based on the child, take what we need to properly propogate the type; variables always get a width
+            let (child_override_kind, child_kind, child_signed, child_width) = get_child_kind!(**expr, symtable, in_signed, in_width, error);
+            let range = get_range(expr);
+            if operator == AssignOperator::Assign {
+                *override_kind = child_override_kind;
+                *kind = child_kind;
+                *signed = child_signed;
+                *width = child_width.or(settings.width);
+            } else {
+                // A compound assignment combines the variable with the child like a binary operation, so get the variable's current type
+                let Variable{ kind: var_kind, value: _, signed: var_signed, width: var_width, end: var_end } = *symtable.get(identifier).unwrap();
+                if var_kind == ValueKind::Undefined {
+                    eprintln!("   {}: Identifier '{}' is defined, but not initialized yet.", pos1, identifier);
+                    *error = true;
+                }
+                if var_end.is_some() {
+                    eprintln!("   {}: Cannot use a compound assignment on range '{}'.", pos1, identifier);
+                    *error = true;
+                }
+                expect_range!(**expr, false, error);
+
+                // The variable keeps its representation unless the child is casted, and booleans become numbers
+                *override_kind = child_override_kind;
+                *kind = if child_override_kind || var_kind == ValueKind::Boolean { child_kind } else { var_kind };
+                if *kind == ValueKind::Boolean { *kind = ValueKind::Decimal; }
+                *signed = var_signed || child_signed;
+                *width = (if var_width > child_width { var_width } else { child_width }).or(settings.width);
+            }
 
             // With this info, update the entry for this type
-            symtable.get_mut(identifier).unwrap().0 = *kind;
+            let var = symtable.get_mut(identifier).unwrap();
+            var.kind   = *kind;
+            var.signed = *signed;
+            var.width  = *width;
+            // Also already note whether it's a range; its actual end is only known once it's computed
+            var.end    = if range { Some(0) } else { None };
         }
-        ASTNode::BinOpLow{ ref mut override_kind, ref mut kind, operator: _, ref mut left, ref mut right, pos1, pos2: _ } |
-        ASTNode::BinOpHigh{ ref mut override_kind, ref mut kind, operator: _, ref mut left, ref mut right, pos1, pos2: _ } => {
-            // Traverse to resolve the children's type
-            **left  = traverse_node(*left.clone(), symtable, error);
-            **right = traverse_node(*right.clone(), symtable, error);
+        ASTNode::BinOpLow{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator: _, ref mut left, ref mut right, pos1, pos2: _ } |
+        ASTNode::BinOpHigh{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator: _, ref mut left, ref mut right, pos1, pos2: _ } |
+        ASTNode::BinOpShift{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator: _, ref mut left, ref mut right, pos1, pos2: _ } |
+        ASTNode::BinOpBitwise{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator: _, ref mut left, ref mut right, pos1, pos2: _ } |
+        ASTNode::Range{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, by_length: _, start: ref mut left, end: ref mut right, pos1, pos2: _ } => {
+            // Traverse to resolve the children's type, which may only be values
+            **left  = traverse_node(*left.clone(), symtable, settings, in_signed, in_width, error);
+            **right = traverse_node(*right.clone(), symtable, settings, in_signed, in_width, error);
+            expect_range!(**left, false, error);
+            expect_range!(**right, false, error);
 
             // Read the properties from the children
-            let (left_override_kind, left_kind)   = get_child_kind!(**left, symtable, error);
-            let (right_override_kind, right_kind) = get_child_kind!(**right, symtable, error);
+            let (left_override_kind, left_kind, left_signed, left_width)     = get_child_kind!(**left, symtable, in_signed, in_width, error);
+            let (right_override_kind, right_kind, right_signed, right_width) = get_child_kind!(**right, symtable, in_signed, in_width, error);
+
+            // The operation is signed if either of its operands is, and takes the widest width of the two
+            *signed = left_signed || right_signed;
+            *width = if left_width > right_width { left_width } else { right_width };
 
             // Now decide what to do
             if left_override_kind && right_override_kind && left_kind != right_kind {
@@ -130,12 +251,193 @@ fn traverse_node(mut node: ASTNode, symtable: &mut SymbolTable, error: &mut bool
                 *override_kind = true;
                 *kind = left_kind;
             } else {
-                // Neither; still ambigious, but deal with it silently this time.
+                // Neither; still ambigious, but deal with it silently this time (preferring anything over a boolean).
                 *override_kind = false;
-                *kind = left_kind;
+                *kind = if left_kind == ValueKind::Boolean { right_kind } else { left_kind };
+            }
+
+            // Unless they're combined bitwise, booleans are simply numbers when used in a computation
+            if *kind == ValueKind::Boolean && !bitwise { *kind = ValueKind::Decimal; }
+        }
+        ASTNode::BinOpCmp{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator, ref mut left, ref mut right, pos1: _, pos2: _ } => {
+            // Traverse to resolve the children's type, which are values except for the righthand-side of 'in'
+            **left  = traverse_node(*left.clone(), symtable, settings, in_signed, in_width, error);
+            **right = traverse_node(*right.clone(), symtable, settings, in_signed, in_width, error);
+            expect_range!(**left, false, error);
+            expect_range!(**right, operator == ComparisonOperator::In, error);
+            // Still read the children's types to make sure they're initialized
+            let _ = get_child_kind!(**left, symtable, in_signed, in_width, error);
+            let _ = get_child_kind!(**right, symtable, in_signed, in_width, error);
+
+            // The operands are compared with their own signedness, but the result is always an (unsigned) boolean that fits any width
+            *override_kind = false;
+            *kind = ValueKind::Boolean;
+            *signed = false;
+            *width = ValueWidth::Undefined;
+        }
+        ASTNode::Ternary{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, ref mut cond, ref mut if_true, ref mut if_false, pos1, pos2: _ } => {
+            // Traverse to resolve the children's type
+            **cond     = traverse_node(*cond.clone(), symtable, settings, in_signed, in_width, error);
+            **if_true  = traverse_node(*if_true.clone(), symtable, settings, in_signed, in_width, error);
+            **if_false = traverse_node(*if_false.clone(), symtable, settings, in_signed, in_width, error);
+            let _ = get_child_kind!(**cond, symtable, in_signed, in_width, error);
+            expect_range!(**cond, false, error);
+            expect_range!(**if_false, get_range(if_true), error);
+
+            // Read the properties from the branches
+            let (true_override_kind, true_kind, true_signed, true_width)     = get_child_kind!(**if_true, symtable, in_signed, in_width, error);
+            let (false_override_kind, false_kind, false_signed, false_width) = get_child_kind!(**if_false, symtable, in_signed, in_width, error);
+
+            // The result must be able to hold either branch, so it's signed if either is and takes the widest width of the two
+            *signed = true_signed || false_signed;
+            *width = if true_width > false_width { true_width } else { false_width };
+
+            // Decide on the kind like the binary operators do, except that booleans stay booleans if both branches are
+            if true_override_kind && false_override_kind && true_kind != false_kind {
+                eprintln!("   {}: Ambigious typing: casted to both {:?} (true branch) and {:?} (false branch); choosing left.", pos1, true_kind, false_kind);
+                *override_kind = true;
+                *kind = true_kind;
+            } else if !true_override_kind && false_override_kind {
+                *override_kind = true;
+                *kind = false_kind;
+            } else if true_override_kind && !false_override_kind {
+                *override_kind = true;
+                *kind = true_kind;
+            } else {
+                *override_kind = false;
+                *kind = if true_kind == ValueKind::Boolean { false_kind } else { true_kind };
             }
         }
 
+        ASTNode::UnaryOp{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, operator: _, ref mut expr, pos1: _, pos2: _ } => {
+            // Traverse through the child to resolve
+            **expr = traverse_node(*expr.clone(), symtable, settings, in_signed, in_width, error);
+            expect_range!(**expr, false, error);
+
+            // Simply take on the child's type, except that a negated or flipped boolean is just a number
+            let (child_override_kind, child_kind, child_signed, child_width) = get_child_kind!(**expr, symtable, in_signed, in_width, error);
+            *override_kind = child_override_kind;
+            *kind = if child_kind == ValueKind::Boolean { ValueKind::Decimal } else { child_kind };
+            *signed = child_signed;
+            *width = child_width;
+        }
+        ASTNode::Call{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, ref identifier, ref mut args, pos1, pos2: _ } => {
+            // Traverse to resolve the arguments' types, which should all be either values or ranges depending on the function
+            let builtin = builtins::get(identifier).unwrap();
+            // Byte swaps work on values of their own width, so their constants take on that width (like in a cast)
+            let args_width = if let BuiltinFunc::Swap(swap_width) = builtin.func { swap_width } else { in_width };
+            for arg in args.iter_mut() {
+                // Float functions read their float constants directly, as long as they fit in the function's float
+                if let (BuiltinFunc::FloatToBits(func, _), Some(value)) = (&builtin.func, get_float(arg)) {
+                    if func(&[value]).is_err() {
+                        eprintln!("   {}: Float constant '{:?}' is too large.", arg.pos().0, value);
+                        *error = true;
+                    }
+                    continue;
+                }
+                *arg = traverse_node(arg.clone(), symtable, settings, in_signed, args_width, error);
+                expect_range!(*arg, builtin.takes_ranges(), error);
+            }
+
+            // Just like the binary operators, the call is signed if any argument is, takes the widest width and prefers the leftmost (overriding) kind
+            *override_kind = false;
+            *kind = ValueKind::Undefined;
+            *signed = false;
+            *width = ValueWidth::Undefined;
+            for (i, arg) in args.iter().enumerate() {
+                let (arg_override_kind, arg_kind, arg_signed, arg_width) = get_child_kind!(*arg, symtable, in_signed, args_width, error);
+                *signed = *signed || arg_signed;
+                if arg_width > *width { *width = arg_width; }
+
+                // Decide on the kind
+                if i == 0 || (arg_override_kind && !*override_kind) {
+                    *override_kind = arg_override_kind;
+                    *kind = arg_kind;
+                } else if arg_override_kind && *kind != arg_kind {
+                    eprintln!("   {}: Ambigious typing: casted to both {:?} (argument 1) and {:?} (argument {}); choosing left.", pos1, *kind, arg_kind, i + 1);
+                }
+            }
+
+            // The result of a function is a number, even if it's called with booleans, unless the function itself results in a boolean
+            if *kind == ValueKind::Boolean { *kind = ValueKind::Decimal; }
+            if let BuiltinFunc::RangeToBool(_) = builtin.func {
+                *override_kind = false;
+                *kind = ValueKind::Boolean;
+                *signed = false;
+                *width = ValueWidth::Undefined;
+            }
+            // Float functions always result in the unsigned bits of a float, which are best shown as hexadecimal
+            if let BuiltinFunc::FloatToBits(_, float_width) = builtin.func {
+                *override_kind = false;
+                *kind = ValueKind::Hexadecimal;
+                *signed = false;
+                *width = float_width;
+            }
+            // Reading and swapping functions always result in a value of the width they read or swap
+            if let BuiltinFunc::Read(func_width, _) | BuiltinFunc::Swap(func_width) = builtin.func {
+                *width = func_width;
+            }
+        }
+        ASTNode::Cast{ ref mut override_kind, ref mut kind, ref mut signed, width, ref mut expr, pos1: _, pos2: _ } => {
+            // Traverse through the child to resolve, where constants take on the width we cast to
+            **expr = traverse_node(*expr.clone(), symtable, settings, in_signed, width, error);
+            expect_range!(**expr, false, error);
+
+            // Take on the child's type, but keep our own width
+            let (child_override_kind, child_kind, child_signed, _) = get_child_kind!(**expr, symtable, in_signed, width, error);
+            *override_kind = child_override_kind;
+            *kind = child_kind;
+            *signed = child_signed;
+        }
+        ASTNode::Slice{ ref mut override_kind, ref mut kind, ref mut signed, ref mut width, bits: _, ref mut expr, ref mut hi, ref mut lo, pos1: _, pos2: _ } => {
+            // Traverse through the value and the bits to resolve, which should all be values
+            **expr = traverse_node(*expr.clone(), symtable, settings, in_signed, in_width, error);
+            expect_range!(**expr, false, error);
+            **hi = traverse_node(*hi.clone(), symtable, settings, in_signed, in_width, error);
+            expect_range!(**hi, false, error);
+            if let Some(lo) = lo {
+                **lo = traverse_node(*lo.clone(), symtable, settings, in_signed, in_width, error);
+                expect_range!(**lo, false, error);
+            }
+
+            // Take on the value's type, except that the extracted bits are never signed (and a sliced boolean is just a number)
+            let (child_override_kind, child_kind, _, child_width) = get_child_kind!(**expr, symtable, in_signed, in_width, error);
+            *override_kind = child_override_kind;
+            *kind = if child_kind == ValueKind::Boolean { ValueKind::Decimal } else { child_kind };
+            *signed = false;
+            *width = child_width;
+        }
+
+        ASTNode::MonOp{ kind, ref mut signed, ref mut width, ref mut expr, pos1, pos2: _ } => {
+            // Traverse through the child to resolve; if we convert to a signed value, the whole child is computed as signed
+            let in_signed = in_signed || *signed;
+            **expr = traverse_node(*expr.clone(), symtable, settings, in_signed, in_width, error);
+
+            // Keep the kind we're converting to, but it's signed if either we convert to a signed value or the child already is one
+            let (_, _, child_signed, child_width) = get_child_kind!(**expr, symtable, in_signed, in_width, error);
+            *signed = child_signed;
+            *width = child_width;
+
+            // Constants that are shown as a float without being given a width are read as a 32-bit float if they fit in one (e.g., 'float 0x3F800000')
+            if kind == ValueKind::Float && *width == ValueWidth::Undefined {
+                if let Some(value) = get_const(expr) {
+                    if ValueWidth::U32.fits(value, false) { *width = ValueWidth::U32; }
+                }
+            }
+
+            // Only 32- and 64-bit values have a float to show
+            let float_width = width.or(settings.width);
+            if kind == ValueKind::Float && float_width != ValueWidth::U32 && float_width != ValueWidth::U64 {
+                eprintln!("   {}: Only 32- and 64-bit values can be shown as a float, not {}-bit values.", pos1, float_width.bits());
+                *error = true;
+            }
+        }
+        ASTNode::Float{ value: _, pos1, pos2: _ } => {
+            // Float constants are only allowed as the arguments of float functions, which read them directly
+            eprintln!("   {}: Float constants can only be given to float functions (e.g., 'f32bits(1.5)').", pos1);
+            *error = true;
+        }
+
         // Ignore the rest
         _ => {}
     }
@@ -154,13 +456,19 @@ fn traverse_node(mut node: ASTNode, symtable: &mut SymbolTable, error: &mut bool
 /// **Arguments**
 ///  * `ast`: The AST to traverse.
 ///  * `symbol_table`: The symbol table that we use to keep track of identifiers.
+///  * `settings`: The Settings that determine the signedness and width of values.
 /// 
 /// **Returns**  
 /// The node to traverse, or else a replacement if the algorithm deems it necessary.
-pub fn traverse(ast: ASTNode, symbol_table: &mut SymbolTable) -> Option<ASTNode> {
+pub fn traverse(ast: ASTNode, symbol_table: &mut SymbolTable, settings: &Settings) -> Option<ASTNode> {
     // Simply return the traverse_node call
     let mut error = false;
-    let new_ast = traverse_node(ast, symbol_table, &mut error);
+    let mut new_ast = traverse_node(ast, symbol_table, settings, settings.signed, ValueWidth::Undefined, &mut error);
     if error { return None };
+
+    // If the result has no width yet, it gets the global one
+    if let ASTNode::Expr{ override_kind: _, kind: _, signed: _, ref mut width, range: _, expr: _, pos1: _, pos2: _ } = new_ast {
+        *width = width.or(settings.width);
+    }
     return Some(new_ast);
 }